@@ -1,14 +1,55 @@
 use crate::{
-    config::Config,
+    config::{with_offsets, Config},
     opts::{Opts, Subcommands},
 };
-use anyhow::{Error, Result};
+use anyhow::Result;
 use chrono::prelude::*;
 use chrono_tz::Tz;
 use colored::*;
 use dateparser::DateTimeUtc;
-use prettytable::{row, Table};
-use std::io;
+use prettytable::{row, Row, Table};
+use std::{error, fmt, io};
+
+/// Error from running `belt`, and its kind decides the exit code.
+#[derive(Debug)]
+pub enum AppError {
+    /// A given time could not be read or parsed, exits with 2.
+    Time(String),
+    /// A time zone or the config is invalid, or the config could not be changed, exits with 3.
+    Config(String),
+    /// Any other error, such as failing to write the output, exits with 1.
+    Other(anyhow::Error),
+}
+
+impl AppError {
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            AppError::Time(_) => 2,
+            AppError::Config(_) => 3,
+            AppError::Other(_) => 1,
+        }
+    }
+}
+
+impl fmt::Display for AppError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AppError::Time(msg) | AppError::Config(msg) => write!(f, "{}", msg),
+            AppError::Other(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl error::Error for AppError {}
+
+impl From<anyhow::Error> for AppError {
+    fn from(err: anyhow::Error) -> Self {
+        match err.downcast::<AppError>() {
+            Ok(err) => err,
+            Err(err) => AppError::Other(err),
+        }
+    }
+}
 
 pub struct App<'a, T> {
     pub opts: &'a Opts,
@@ -23,37 +64,131 @@ where
         Self { opts, config }
     }
 
-    pub fn show_datetime(&mut self) -> Result<()> {
+    pub fn show_datetime(&mut self) -> Result<(), AppError> {
+        Ok(self.show_datetime_from(io::stdin().lock())?)
+    }
+
+    // Same as `show_datetime`, but a TIME of "-" is read as one line from `stdin`.
+    fn show_datetime_from<R: io::BufRead>(&mut self, mut stdin: R) -> Result<()> {
         if self.opts.subcommands.is_some() {
             // skip showing datetime when there is a subcommand
             return Ok(());
         }
 
-        let mut to_show = Utc::now();
-        if let Some(time) = &self.opts.time {
-            to_show = time.parse::<DateTimeUtc>()?.0;
+        let times = &self.opts.time;
+        if times.len() > 1 && times.iter().any(|time| time == "-") {
+            return Err(AppError::Time(
+                "a time from stdin cannot be mixed with other times".to_string(),
+            )
+            .into());
+        }
+
+        let mut line = String::new();
+        let time = match times.first().map(|time| time.as_str()) {
+            Some("-") => {
+                stdin
+                    .read_line(&mut line)
+                    .map_err(|err| AppError::Time(format!("could not read stdin: {}", err)))?;
+                match line.trim() {
+                    "" => {
+                        return Err(AppError::Time(
+                            "no time to show was read from stdin".to_string(),
+                        )
+                        .into())
+                    }
+                    time => Some(time),
+                }
+            }
+            time => time,
+        };
+
+        match time {
+            None => self.show_one(Utc::now(), false),
+            Some(time) if times.len() == 1 => {
+                let parsed = time
+                    .parse::<DateTimeUtc>()
+                    .map_err(|err| AppError::Time(err.to_string()))?;
+                self.show_one(parsed.0, true)
+            }
+            Some(_) => {
+                // one section per time, and a time that fails to parse does not stop the others
+                let mut failed = 0;
+                for time in times {
+                    writeln!(self.config.out, "{}", time.cyan().bold())?;
+                    match time.parse::<DateTimeUtc>() {
+                        Ok(parsed) => self.show_one(parsed.0, true)?,
+                        Err(err) => {
+                            failed += 1;
+                            writeln!(
+                                self.config.out,
+                                "{}",
+                                format!("Could not parse time: {}", err).red().bold()
+                            )?
+                        }
+                    }
+                }
+                if failed > 0 {
+                    return Err(AppError::Time(format!(
+                        "{} of {} times could not be parsed",
+                        failed,
+                        times.len()
+                    ))
+                    .into());
+                }
+                Ok(())
+            }
         }
+    }
 
+    // Show a single datetime, `given` is false when it is the current time.
+    fn show_one(&mut self, to_show: DateTime<Utc>, given: bool) -> Result<()> {
         let local = to_show.with_timezone(&Local);
-        let ymd_hms_z = "%Y-%m-%d %H:%M:%S %z";
+        // keep the sub-second precision when the given time has one
+        let ymd_hms_z = if !given || to_show.nanosecond() == 0 {
+            "%Y-%m-%d %H:%M:%S %z"
+        } else {
+            "%Y-%m-%d %H:%M:%S%.f %z"
+        };
         let ymd_hm_z = "%Y-%m-%d %H:%M %Z";
 
+        if self.opts.relative {
+            writeln!(self.config.out, "({})", humanize(to_show - Utc::now()))?;
+        }
+
+        if !self.opts.to.is_empty() {
+            return self.show_converted(to_show, ymd_hms_z, ymd_hm_z);
+        }
+
         if self.opts.short {
             writeln!(self.config.out, "{}", local.format(ymd_hms_z))?;
         } else {
             let mut table = Table::new();
-            table.set_titles(row!["Zone", "Date & Time"]);
-            table.add_row(row![
-                "Local",
-                format!("{}\n{}", local.format(ymd_hms_z), local.format("%s"))
-            ]);
-            for timezone in &self.config.store.timezones {
-                let tz: Tz = timezone.parse().map_err(Error::msg)?;
+            table.set_titles(self.titles());
+            table.add_row(self.with_extra(
+                row![
+                    "Local",
+                    format!("{}\n{}", local.format(ymd_hms_z), local.format("%s"))
+                ],
+                &local,
+            ));
+            let timezones = &self.config.store.timezones;
+            let mut last_offset = None;
+            for (timezone, tz, utc_offset) in
+                with_offsets(timezones, &to_show.naive_utc(), self.opts.sort_offset)
+                    .map_err(|err| AppError::Config(err.to_string()))?
+            {
+                if self.opts.group && last_offset.is_some_and(|last| last != utc_offset) {
+                    table.add_empty_row();
+                }
+                last_offset = Some(utc_offset);
                 let dtz = to_show.with_timezone(&tz);
-                table.add_row(row![
-                    timezone,
-                    format!("{}\n{}", dtz.format(ymd_hms_z), dtz.format(ymd_hm_z))
-                ]);
+                table.add_row(self.with_extra(
+                    row![
+                        timezone,
+                        format!("{}\n{}", dtz.format(ymd_hms_z), dtz.format(ymd_hm_z))
+                    ],
+                    &dtz,
+                ));
             }
             table.print(&mut self.config.out)?;
         }
@@ -61,23 +196,100 @@ where
         Ok(())
     }
 
-    pub fn handle_subcommands(&mut self) -> Result<()> {
+    // Show the datetime converted to each time zone given by `--to`, bypassing the configured list.
+    fn show_converted(
+        &mut self,
+        to_show: DateTime<Utc>,
+        ymd_hms_z: &str,
+        ymd_hm_z: &str,
+    ) -> Result<()> {
+        let timezones = self
+            .opts
+            .to
+            .iter()
+            .map(|timezone| {
+                timezone.parse::<Tz>().map_err(|err| {
+                    AppError::Config(format!(
+                        "{}, use an IANA time zone name such as America/New_York",
+                        err
+                    ))
+                    .into()
+                })
+            })
+            .collect::<Result<Vec<Tz>>>()?;
+
+        if self.opts.short {
+            for tz in timezones {
+                let dtz = to_show.with_timezone(&tz);
+                writeln!(self.config.out, "{}", dtz.format(ymd_hms_z))?;
+            }
+        } else {
+            let mut table = Table::new();
+            table.set_titles(self.titles());
+            for (timezone, tz) in self.opts.to.iter().zip(timezones) {
+                let dtz = to_show.with_timezone(&tz);
+                table.add_row(self.with_extra(
+                    row![
+                        timezone,
+                        format!("{}\n{}", dtz.format(ymd_hms_z), dtz.format(ymd_hm_z))
+                    ],
+                    &dtz,
+                ));
+            }
+            table.print(&mut self.config.out)?;
+        }
+
+        Ok(())
+    }
+
+    fn titles(&self) -> Row {
+        let mut titles = row!["Zone", "Date & Time"];
+        if self.opts.extra {
+            titles.extend(["Week", "Day", "Weekday"]);
+        }
+        titles
+    }
+
+    // Append the ISO week, day of year and weekday columns when `--extra` is set.
+    fn with_extra<Z>(&self, mut row: Row, dt: &DateTime<Z>) -> Row
+    where
+        Z: TimeZone,
+        Z::Offset: fmt::Display,
+    {
+        if self.opts.extra {
+            row.extend([dt.format("%V"), dt.format("%j"), dt.format("%A")]);
+        }
+        row
+    }
+
+    pub fn handle_subcommands(&mut self) -> Result<(), AppError> {
+        // anything that fails while changing the config is a config error
+        self.handle_config()
+            .map_err(|err| AppError::Config(err.to_string()))
+    }
+
+    fn handle_config(&mut self) -> Result<()> {
         if let Some(subcommands) = &self.opts.subcommands {
             match subcommands {
                 Subcommands::Config(c) => {
                     if c.list {
                         let path = self.config.path();
                         writeln!(self.config.out, "{}", path.cyan().bold())?;
-                        self.config.list()?;
+                        self.config.list(self.opts.sort_offset, self.opts.group)?;
                     } else if c.reset {
                         self.config.reset()?;
-                        self.config.list()?;
+                        self.config.list(self.opts.sort_offset, self.opts.group)?;
                     } else if let Some(add) = &c.add {
                         self.config.add(add)?;
-                        self.config.list()?;
+                        self.config.list(self.opts.sort_offset, self.opts.group)?;
                     } else if let Some(delete) = &c.delete {
                         self.config.delete(delete)?;
-                        self.config.list()?;
+                        self.config.list(self.opts.sort_offset, self.opts.group)?;
+                    } else if let Some(export) = &c.export {
+                        self.config.export(export)?;
+                    } else if let Some(import) = &c.import {
+                        self.config.import(import)?;
+                        self.config.list(self.opts.sort_offset, self.opts.group)?;
                     }
                 }
             }
@@ -86,26 +298,54 @@ where
     }
 }
 
+// Describe a duration from now in its largest whole unit, such as "in 3 hours" or "2 days ago".
+fn humanize(duration: chrono::Duration) -> String {
+    let seconds = duration.num_seconds();
+    let (count, unit) = [
+        (365 * 24 * 3600, "year"),
+        (30 * 24 * 3600, "month"),
+        (24 * 3600, "day"),
+        (3600, "hour"),
+        (60, "minute"),
+        (1, "second"),
+    ]
+    .into_iter()
+    .map(|(unit_seconds, unit)| (seconds.abs() / unit_seconds, unit))
+    .find(|&(count, _)| count > 0)
+    .unwrap_or((0, "second"));
+
+    let plural = if count == 1 { "" } else { "s" };
+    match seconds {
+        0 => "now".to_string(),
+        s if s > 0 => format!("in {} {}{}", count, unit, plural),
+        _ => format!("{} {}{} ago", count, unit, plural),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::opts::OptsConfig;
     use rand::{thread_rng, Rng};
     use regex::Regex;
-    use std::{thread::sleep, time::Duration};
+    use std::{io::Cursor, thread::sleep, time::Duration};
+
+    // Config is loaded from the same file by tests running in parallel, so a load that fails
+    // while another test writes the file is retried once after a random delay. The first load
+    // writes to a throwaway buffer, so that only the config returned borrows `buf`.
+    fn test_config<'a>(app: &str, buf: &'a mut Vec<u8>) -> Config<'a, Vec<u8>> {
+        if Config::new(app, &mut Vec::new()).is_err() {
+            sleep(Duration::from_millis(thread_rng().gen_range(100..500)));
+        }
+        Config::new(app, buf).expect("failed to create config")
+    }
 
     #[test]
     fn test_app_show_datetime() {
         let mut opts = Opts::new();
         opts.app = "unit-test".to_string();
         let mut buf = vec![0u8];
-        let mut config = match Config::new(&opts.app, &mut buf) {
-            Ok(config) => config,
-            Err(_) => {
-                sleep(Duration::from_millis(thread_rng().gen_range(100..500)));
-                Config::new(&opts.app, &mut buf).expect("failed to create config")
-            }
-        };
+        let mut config = test_config(&opts.app, &mut buf);
         let timezones = config.store.timezones.clone();
         let num_timezones = timezones.len();
         let mut app = App::new(&opts, &mut config);
@@ -121,18 +361,241 @@ mod tests {
         assert_eq!(re.find_iter(&printed).count(), num_timezones + 1); // num_timezones + local
     }
 
+    #[test]
+    fn test_app_show_datetime_with_subsecond() {
+        let opts = Opts {
+            subcommands: None,
+            time: vec!["2021-05-14 18:51:00.123456 UTC".to_string()],
+            to: vec![],
+            short: false,
+            extra: false,
+            relative: false,
+            sort_offset: false,
+            group: false,
+            app: "unit-test".to_string(),
+        };
+        let mut buf = vec![0u8];
+        let mut config = test_config(&opts.app, &mut buf);
+        let num_timezones = config.store.timezones.len();
+        let mut app = App::new(&opts, &mut config);
+
+        app.show_datetime().expect("failed showing time");
+
+        let printed = String::from_utf8_lossy(&buf);
+        let re = Regex::new(r"[0-9]{4}-[0-9]{2}-[0-9]{2} [0-9]{2}:[0-9]{2}:00\.123456 [0-9-+]{5}")
+            .expect("failed to parse regex");
+        assert_eq!(re.find_iter(&printed).count(), num_timezones + 1); // num_timezones + local
+    }
+
+    #[test]
+    fn test_app_show_datetime_with_unparseable_time() {
+        let opts = Opts {
+            subcommands: None,
+            time: vec!["not a time".to_string()],
+            to: vec![],
+            short: true,
+            extra: false,
+            relative: false,
+            sort_offset: false,
+            group: false,
+            app: "unit-test".to_string(),
+        };
+        let mut buf = vec![0u8];
+        let mut config = test_config(&opts.app, &mut buf);
+        let mut app = App::new(&opts, &mut config);
+
+        let err = app.show_datetime().expect_err("showed an unparseable time");
+        assert!(matches!(err, AppError::Time(_)));
+        assert_eq!(err.exit_code(), 2);
+        assert_eq!(err.to_string(), "not a time did not match any formats.");
+    }
+
+    #[test]
+    fn test_app_show_datetime_converted() {
+        let opts = Opts {
+            subcommands: None,
+            time: vec!["2021-05-01 10:00 UTC".to_string()],
+            to: vec!["America/New_York".to_string(), "Asia/Tokyo".to_string()],
+            short: false,
+            extra: false,
+            relative: false,
+            sort_offset: false,
+            group: false,
+            app: "unit-test".to_string(),
+        };
+        let mut buf = vec![0u8];
+        let mut config = test_config(&opts.app, &mut buf);
+        let mut app = App::new(&opts, &mut config);
+
+        app.show_datetime().expect("failed showing time");
+
+        let printed = String::from_utf8_lossy(&buf);
+        assert!(printed.contains("America/New_York"));
+        assert!(printed.contains("2021-05-01 06:00:00 -0400"));
+        assert!(printed.contains("2021-05-01 06:00 EDT"));
+        assert!(printed.contains("Asia/Tokyo"));
+        assert!(printed.contains("2021-05-01 19:00:00 +0900"));
+        assert!(!printed.contains("Local"));
+    }
+
+    #[test]
+    fn test_app_show_datetime_converted_with_unknown_timezone() {
+        let opts = Opts {
+            subcommands: None,
+            time: vec!["2021-05-01 10:00 UTC".to_string()],
+            to: vec!["America/New_Yrok".to_string()],
+            short: false,
+            extra: false,
+            relative: false,
+            sort_offset: false,
+            group: false,
+            app: "unit-test".to_string(),
+        };
+        let mut buf = vec![0u8];
+        let mut config = test_config(&opts.app, &mut buf);
+        let mut app = App::new(&opts, &mut config);
+
+        let err = app
+            .show_datetime()
+            .expect_err("showed an unknown time zone");
+        assert_eq!(err.exit_code(), 3);
+        assert!(err.to_string().contains("America/New_Yrok"));
+    }
+
+    #[test]
+    fn test_app_show_datetime_with_extra() {
+        let opts = Opts {
+            subcommands: None,
+            time: vec!["2021-05-12 16:44 UTC".to_string()],
+            to: vec!["Asia/Tokyo".to_string()],
+            short: false,
+            extra: true,
+            relative: false,
+            sort_offset: false,
+            group: false,
+            app: "unit-test".to_string(),
+        };
+        let mut buf = vec![0u8];
+        let mut config = test_config(&opts.app, &mut buf);
+        let mut app = App::new(&opts, &mut config);
+
+        app.show_datetime().expect("failed showing time");
+
+        let printed = String::from_utf8_lossy(&buf);
+        assert!(printed.contains("Week"));
+        assert!(printed.contains("| 19 "));
+        assert!(printed.contains("| 133 "));
+        assert!(printed.contains("Thursday"));
+    }
+
+    #[test]
+    fn test_app_show_datetime_relative() {
+        let in_3_hours = (Utc::now() + chrono::Duration::minutes(3 * 60 + 30))
+            .format("%Y-%m-%d %H:%M:%S UTC")
+            .to_string();
+        let opts = Opts {
+            subcommands: None,
+            time: vec![in_3_hours],
+            to: vec!["UTC".to_string()],
+            short: true,
+            extra: false,
+            relative: true,
+            sort_offset: false,
+            group: false,
+            app: "unit-test".to_string(),
+        };
+        let mut buf = vec![0u8];
+        let mut config = test_config(&opts.app, &mut buf);
+        let mut app = App::new(&opts, &mut config);
+
+        app.show_datetime().expect("failed showing time");
+
+        let printed = String::from_utf8_lossy(&buf);
+        assert!(printed.contains("(in 3 hours)"));
+    }
+
+    #[test]
+    fn test_humanize() {
+        use chrono::Duration;
+
+        let test_cases = [
+            (Duration::zero(), "now"),
+            (Duration::seconds(1), "in 1 second"),
+            (Duration::seconds(-45), "45 seconds ago"),
+            (Duration::minutes(90), "in 1 hour"),
+            (Duration::hours(-49), "2 days ago"),
+            (Duration::days(45), "in 1 month"),
+            (Duration::days(-800), "2 years ago"),
+        ];
+
+        for &(duration, expected) in test_cases.iter() {
+            assert_eq!(humanize(duration), expected);
+        }
+    }
+
+    #[test]
+    fn test_app_show_datetime_from_stdin() {
+        let opts = Opts {
+            subcommands: None,
+            time: vec!["-".to_string()],
+            to: vec!["UTC".to_string()],
+            short: true,
+            extra: false,
+            relative: false,
+            sort_offset: false,
+            group: false,
+            app: "unit-test".to_string(),
+        };
+        let mut buf = vec![0u8];
+        let mut config = test_config(&opts.app, &mut buf);
+        let mut app = App::new(&opts, &mut config);
+
+        app.show_datetime_from(Cursor::new("2021-05-01 10:00 UTC\n"))
+            .expect("failed showing time");
+        app.show_datetime_from(Cursor::new(""))
+            .expect_err("showed time from empty stdin");
+
+        let printed = String::from_utf8_lossy(&buf);
+        assert!(printed.contains("2021-05-01 10:00:00 +0000"));
+    }
+
+    #[test]
+    fn test_app_show_datetime_with_multiple_times() {
+        let opts = Opts {
+            subcommands: None,
+            time: vec![
+                "2021-05-01 10:00 UTC".to_string(),
+                "not a time".to_string(),
+                "2021-06-01 10:00 UTC".to_string(),
+            ],
+            to: vec!["UTC".to_string()],
+            short: true,
+            extra: false,
+            relative: false,
+            sort_offset: false,
+            group: false,
+            app: "unit-test".to_string(),
+        };
+        let mut buf = vec![0u8];
+        let mut config = test_config(&opts.app, &mut buf);
+        let mut app = App::new(&opts, &mut config);
+
+        let err = app.show_datetime().expect_err("showed an unparseable time");
+        assert_eq!(err.exit_code(), 2);
+        assert_eq!(err.to_string(), "1 of 3 times could not be parsed");
+
+        let printed = String::from_utf8_lossy(&buf);
+        assert!(printed.contains("2021-05-01 10:00:00 +0000"));
+        assert!(printed.contains("not a time did not match any formats"));
+        assert!(printed.contains("2021-06-01 10:00:00 +0000"));
+    }
+
     #[test]
     fn test_app_handle_subcommands() {
         let mut opts = Opts::new();
         opts.app = "unit-test".to_string();
         let mut buf = vec![0u8];
-        let mut config = match Config::new(&opts.app, &mut buf) {
-            Ok(config) => config,
-            Err(_) => {
-                sleep(Duration::from_millis(thread_rng().gen_range(100..500)));
-                Config::new(&opts.app, &mut buf).expect("failed to create config")
-            }
-        };
+        let mut config = test_config(&opts.app, &mut buf);
         let timezones = config.store.timezones.clone();
         let mut app = App::new(&opts, &mut config);
 
@@ -142,9 +605,16 @@ mod tests {
                 reset: false,
                 add: None,
                 delete: None,
+                export: None,
+                import: None,
             })),
-            time: None,
+            time: vec![],
+            to: vec![],
             short: false,
+            extra: false,
+            relative: false,
+            sort_offset: false,
+            group: false,
             app: opts.app.to_owned(),
         };
         app.opts = &opts;
@@ -156,4 +626,62 @@ mod tests {
             assert!(printed.contains(&tz));
         }
     }
+
+    #[test]
+    fn test_app_handle_subcommands_with_invalid_config() {
+        let app_name = "unit-test".to_string();
+        let mut buf = vec![0u8];
+        let mut config = test_config(&app_name, &mut buf);
+        let missing = std::env::temp_dir().join(format!(
+            "belt-unit-test-missing-{}.toml",
+            thread_rng().gen::<u64>()
+        ));
+        let config_opts = [
+            OptsConfig {
+                list: false,
+                reset: false,
+                add: Some("Bogus/Zone".to_string()),
+                delete: None,
+                export: None,
+                import: None,
+            },
+            OptsConfig {
+                list: false,
+                reset: false,
+                add: None,
+                delete: None,
+                export: None,
+                import: Some(
+                    missing
+                        .to_str()
+                        .expect("failed to get temp path")
+                        .to_string(),
+                ),
+            },
+        ];
+        let opts: Vec<Opts> = config_opts
+            .into_iter()
+            .map(|config_opts| Opts {
+                subcommands: Some(Subcommands::Config(config_opts)),
+                time: vec![],
+                to: vec![],
+                short: false,
+                extra: false,
+                relative: false,
+                sort_offset: false,
+                group: false,
+                app: app_name.clone(),
+            })
+            .collect();
+
+        let mut app = App::new(&opts[0], &mut config);
+        for opts in opts.iter() {
+            app.opts = opts;
+            let err = app
+                .handle_subcommands()
+                .expect_err("changed config with an invalid entry");
+            assert!(matches!(err, AppError::Config(_)));
+            assert_eq!(err.exit_code(), 3);
+        }
+    }
 }
@@ -1,11 +1,11 @@
-use anyhow::{Error, Result};
+use anyhow::{anyhow, Error, Result};
 use chrono::prelude::*;
-use chrono_tz::{OffsetComponents, OffsetName, Tz};
+use chrono_tz::{OffsetComponents, OffsetName, Tz, TZ_VARIANTS};
 use colored::*;
 use directories::ProjectDirs;
 use prettytable::{row, Table};
 use serde::{Deserialize, Serialize};
-use std::io;
+use std::{io, path::Path};
 
 pub struct Config<'a, T> {
     pub store: Store,
@@ -51,12 +51,20 @@ where
             .unwrap_or_default()
     }
 
-    pub fn list(&mut self) -> Result<()> {
+    /// List the time zones, which can be ordered by their current UTC offset, and grouped with a
+    /// separator row between different offsets.
+    pub fn list(&mut self, sort_offset: bool, group: bool) -> Result<()> {
         let now_utc = Local::now().naive_utc();
         let mut table = Table::new();
         table.set_titles(row![l -> "Zone", l -> "Abbr.", r -> "Offset"]);
-        for timezone in &self.store.timezones {
-            let tz: Tz = timezone.parse().map_err(Error::msg)?;
+        let mut last_offset = None;
+        for (timezone, tz, utc_offset) in
+            with_offsets(&self.store.timezones, &now_utc, sort_offset)?
+        {
+            if group && last_offset.is_some_and(|last| last != utc_offset) {
+                table.add_empty_row();
+            }
+            last_offset = Some(utc_offset);
             let offset = tz.offset_from_utc_datetime(&now_utc);
             table.add_row(row![
                 l -> timezone,
@@ -81,11 +89,16 @@ where
                 "{}",
                 format!("Added '{}' to config.", to_add).green().bold()
             )?,
-            Err(err) => writeln!(
-                self.out,
-                "{}",
-                format!("Could not add time zone: {}.", err).red().bold()
-            )?,
+            Err(err) => {
+                if let Some(suggestion) = suggest_timezone(to_add) {
+                    writeln!(
+                        self.out,
+                        "{}",
+                        format!("did you mean: {}?", suggestion).yellow().bold()
+                    )?;
+                }
+                return Err(anyhow!("could not add time zone: {}", err));
+            }
         };
         Ok(())
     }
@@ -100,33 +113,130 @@ where
                     .green()
                     .bold()
             )?,
-            Err(err) => writeln!(
+            Err(err) => return Err(anyhow!("could not delete time zone: {}", err)),
+        };
+        Ok(())
+    }
+
+    pub fn export(&mut self, path: &str) -> Result<()> {
+        match confy::store_path(path, &self.store) {
+            Ok(_) => writeln!(
                 self.out,
                 "{}",
-                format!("Could not delete time zone: {}.", err).red().bold()
+                format!("Exported config to '{}'.", path).green().bold()
             )?,
+            Err(err) => return Err(anyhow!("could not export time zones: {}", err)),
         };
         Ok(())
     }
 
-    pub fn reset(&mut self) -> Result<()> {
-        self.store.timezones = Store::default().timezones;
+    /// Replace the time zones with the ones from an exported file, which is only done when every
+    /// one of them is valid.
+    pub fn import(&mut self, path: &str) -> Result<()> {
+        if !Path::new(path).is_file() {
+            return Err(anyhow!(
+                "could not import time zones: '{}' does not exist",
+                path
+            ));
+        }
+        let store: Store = confy::load_path(path)
+            .map_err(|err| anyhow!("could not import time zones: {}", err))?;
+        let invalid: Vec<&str> = store
+            .timezones
+            .iter()
+            .filter(|timezone| timezone.parse::<Tz>().is_err())
+            .map(|timezone| timezone.as_str())
+            .collect();
+        if !invalid.is_empty() {
+            return Err(anyhow!(
+                "could not import time zones, invalid entries: {}",
+                invalid.join(", ")
+            ));
+        }
+        self.store = store;
         match confy::store(&self.app, None, &self.store) {
             Ok(_) => writeln!(
                 self.out,
                 "{}",
-                "Config has been reset to default.".green().bold()
+                format!("Imported config from '{}'.", path).green().bold()
             )?,
-            Err(err) => writeln!(
+            Err(err) => return Err(anyhow!("could not import time zones: {}", err)),
+        };
+        Ok(())
+    }
+
+    pub fn reset(&mut self) -> Result<()> {
+        self.store.timezones = Store::default().timezones;
+        match confy::store(&self.app, None, &self.store) {
+            Ok(_) => writeln!(
                 self.out,
                 "{}",
-                format!("Could not reset time zones: {}", err).red().bold()
+                "Config has been reset to default.".green().bold()
             )?,
+            Err(err) => return Err(anyhow!("could not reset time zones: {}", err)),
         };
         Ok(())
     }
 }
 
+/// Pairs each time zone with its UTC offset in seconds at the `at` instant, which takes daylight
+/// saving time into account, and orders them by the offset, ascending, when `sort_offset` is set.
+pub fn with_offsets<'t>(
+    timezones: &'t [String],
+    at: &NaiveDateTime,
+    sort_offset: bool,
+) -> Result<Vec<(&'t String, Tz, i32)>> {
+    let mut offsets = timezones
+        .iter()
+        .map(|timezone| {
+            let tz: Tz = timezone.parse().map_err(Error::msg)?;
+            let utc_offset = tz.offset_from_utc_datetime(at).fix().local_minus_utc();
+            Ok((timezone, tz, utc_offset))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    if sort_offset {
+        offsets.sort_by_key(|&(_, _, utc_offset)| utc_offset);
+    }
+    Ok(offsets)
+}
+
+/// Finds the known time zone name closest to a mistyped one: the shortest name that contains it,
+/// case-insensitively, or else the one with the smallest edit distance, if that is close enough.
+fn suggest_timezone(input: &str) -> Option<&'static str> {
+    let input = input.to_lowercase();
+    if input.is_empty() {
+        return None;
+    }
+    let names = TZ_VARIANTS.iter().map(|tz| tz.name());
+    if let Some(name) = names
+        .clone()
+        .filter(|name| name.to_lowercase().contains(&input))
+        .min_by_key(|name| name.len())
+    {
+        return Some(name);
+    }
+    let max_distance = (input.chars().count() / 3).max(2);
+    names
+        .map(|name| (levenshtein(&input, &name.to_lowercase()), name))
+        .filter(|&(distance, _)| distance <= max_distance)
+        .min_by_key(|&(distance, _)| distance)
+        .map(|(_, name)| name)
+}
+
+fn levenshtein(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut curr = vec![i + 1; b.len() + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let cost = usize::from(ca != *cb);
+            curr[j + 1] = (prev[j] + cost).min(prev[j + 1] + 1).min(curr[j] + 1);
+        }
+        prev = curr;
+    }
+    prev[b.len()]
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -164,13 +274,48 @@ mod tests {
         config.reset().expect("failed to reset config store");
         config.out.clear();
 
-        config.list().expect("failed to list configured timezons");
+        config
+            .list(false, false)
+            .expect("failed to list configured timezons");
         let listed = String::from_utf8_lossy(&buf);
         for tz in Store::default().timezones {
             assert!(listed.contains(&tz));
         }
     }
 
+    #[test]
+    fn test_with_offsets() {
+        let timezones = vec![
+            "Europe/London".to_string(),
+            "UTC".to_string(),
+            "Asia/Tokyo".to_string(),
+            "America/New_York".to_string(),
+            "Etc/GMT".to_string(),
+        ];
+        let at = NaiveDate::from_ymd_opt(2021, 5, 1)
+            .and_then(|date| date.and_hms_opt(10, 0, 0))
+            .expect("failed to create datetime");
+
+        let sorted = with_offsets(&timezones, &at, true).expect("failed to sort by offset");
+        let sorted: Vec<(&str, i32)> = sorted
+            .iter()
+            .map(|(timezone, _, utc_offset)| (timezone.as_str(), *utc_offset))
+            .collect();
+        assert_eq!(
+            sorted,
+            vec![
+                ("America/New_York", -4 * 3600),
+                ("UTC", 0),
+                ("Etc/GMT", 0),
+                ("Europe/London", 3600),
+                ("Asia/Tokyo", 9 * 3600),
+            ]
+        );
+
+        let unsorted = with_offsets(&timezones, &at, false).expect("failed to get offsets");
+        assert_eq!(unsorted[0].0, "Europe/London");
+    }
+
     #[test]
     fn test_config_add() {
         let mut buf = vec![0u8];
@@ -188,11 +333,45 @@ mod tests {
             .expect("failed to add Europe/Berlin");
         config.out.clear();
 
-        config.list().expect("failed to list configured timezons");
+        config
+            .list(false, false)
+            .expect("failed to list configured timezons");
         let listed = String::from_utf8_lossy(&buf);
         assert!(listed.contains("Europe/Berlin"));
     }
 
+    #[test]
+    fn test_config_add_suggests_timezone() {
+        let mut buf = vec![0u8];
+        let app = "unit-test";
+        let mut config = match Config::new(app, &mut buf) {
+            Ok(config) => config,
+            Err(_) => {
+                sleep(Duration::from_millis(thread_rng().gen_range(100..500)));
+                Config::new(app, &mut buf).expect("failed to create config")
+            }
+        };
+        config.reset().expect("failed to reset config store");
+        config.out.clear();
+
+        let err = config.add("Europe/Berln").expect_err("added Europe/Berln");
+        assert!(err.to_string().contains("could not add time zone"));
+        let added = String::from_utf8_lossy(&buf);
+        assert!(added.contains("did you mean: Europe/Berlin?"));
+    }
+
+    #[test]
+    fn test_suggest_timezone() {
+        assert_eq!(suggest_timezone("Europe/Berln"), Some("Europe/Berlin"));
+        assert_eq!(
+            suggest_timezone("america/new_yrok"),
+            Some("America/New_York")
+        );
+        assert_eq!(suggest_timezone("tokyo"), Some("Asia/Tokyo"));
+        assert_eq!(suggest_timezone("not a timezone at all"), None);
+        assert_eq!(suggest_timezone(""), None);
+    }
+
     #[test]
     fn test_config_delete() {
         let mut buf = vec![0u8];
@@ -208,11 +387,52 @@ mod tests {
         config.delete("UTC").expect("failed to delete UTC");
         config.out.clear();
 
-        config.list().expect("failed to list configured timezons");
+        config
+            .list(false, false)
+            .expect("failed to list configured timezons");
         let listed = String::from_utf8_lossy(&buf);
         assert!(!listed.contains("UTC"));
     }
 
+    #[test]
+    fn test_config_export_import() {
+        let mut buf = vec![0u8];
+        let app = "unit-test";
+        let mut config = match Config::new(app, &mut buf) {
+            Ok(config) => config,
+            Err(_) => {
+                sleep(Duration::from_millis(thread_rng().gen_range(100..500)));
+                Config::new(app, &mut buf).expect("failed to create config")
+            }
+        };
+        let path =
+            std::env::temp_dir().join(format!("belt-unit-test-{}.toml", thread_rng().gen::<u64>()));
+        let path = path.to_str().expect("failed to get temp path");
+        let exported = Store {
+            timezones: vec!["Asia/Tokyo".to_string(), "Europe/Berlin".to_string()],
+        };
+        config.store = exported;
+        config.export(path).expect("failed to export config");
+        config.reset().expect("failed to reset config store");
+
+        config.import(path).expect("failed to import config");
+        assert_eq!(config.store.timezones, vec!["Asia/Tokyo", "Europe/Berlin"]);
+
+        let invalid = Store {
+            timezones: vec!["UTC".to_string(), "Europe/Berln".to_string()],
+        };
+        confy::store_path(path, &invalid).expect("failed to write invalid config");
+        let err = config.import(path).expect_err("imported invalid config");
+        assert!(err.to_string().contains("invalid entries: Europe/Berln"));
+        assert_eq!(config.store.timezones, vec!["Asia/Tokyo", "Europe/Berlin"]);
+
+        std::fs::remove_file(path).expect("failed to remove exported config");
+        let err = config.import(path).expect_err("imported missing config");
+        assert!(err.to_string().contains("does not exist"));
+
+        config.reset().expect("failed to reset config store");
+    }
+
     #[test]
     fn test_config_reset() {
         let mut buf = vec![0u8];
@@ -232,7 +452,9 @@ mod tests {
         config.reset().expect("failed to reset config store");
         config.out.clear();
 
-        config.list().expect("failed to list configured timezons");
+        config
+            .list(false, false)
+            .expect("failed to list configured timezons");
         let listed = String::from_utf8_lossy(&buf);
         for tz in Store::default().timezones {
             assert!(listed.contains(&tz));
@@ -2,14 +2,26 @@ mod app;
 mod config;
 mod opts;
 
-use crate::{app::App, config::Config, opts::Opts};
-use anyhow::Result;
+use crate::{
+    app::{App, AppError},
+    config::Config,
+    opts::Opts,
+};
+use std::process;
 
-fn main() -> Result<()> {
+fn main() {
     let opts = Opts::new();
+    if let Err(err) = run(&opts) {
+        eprintln!("belt: {}", err);
+        process::exit(err.exit_code());
+    }
+}
+
+fn run(opts: &Opts) -> Result<(), AppError> {
     let mut out = std::io::stdout();
-    let mut config = Config::new(&opts.app, &mut out)?;
-    let mut app = App::new(&opts, &mut config);
+    let mut config =
+        Config::new(&opts.app, &mut out).map_err(|err| AppError::Config(err.to_string()))?;
+    let mut app = App::new(opts, &mut config);
 
     app.show_datetime()?;
     app.handle_subcommands()?;
@@ -3,11 +3,27 @@ use clap::{Parser, Subcommand};
 #[derive(Parser, Debug)]
 #[command(author, about, version)]
 pub struct Opts {
+    /// Times to show, or "-" to read one from stdin
     #[arg(name = "TIME")]
-    pub time: Option<String>,
+    pub time: Vec<String>,
+    /// Convert the time to the given time zone instead of the configured list, can be repeated
+    #[arg(short, long, name = "TIMEZONE")]
+    pub to: Vec<String>,
     /// Provide a terse answer, and default to a verbose form
     #[arg(short, long)]
     pub short: bool,
+    /// Add the ISO week, day of year and weekday to the table
+    #[arg(short, long)]
+    pub extra: bool,
+    /// Show how far the time is from now
+    #[arg(short, long)]
+    pub relative: bool,
+    /// Order time zones by their current UTC offset
+    #[arg(long, global = true)]
+    pub sort_offset: bool,
+    /// Separate time zones with different UTC offsets
+    #[arg(long, global = true)]
+    pub group: bool,
 
     /// Name of the config
     #[arg(short, long, name = "NAME", default_value = "belt")]
@@ -37,6 +53,12 @@ pub struct OptsConfig {
     /// Delete a time zone from the list
     #[arg(short, long, name = "timezone_to_delete")]
     pub delete: Option<String>,
+    /// Export the list of time zones to a TOML file
+    #[arg(short, long, name = "path_to_export")]
+    pub export: Option<String>,
+    /// Import a list of time zones from a TOML file, replacing the existing one
+    #[arg(short, long, name = "path_to_import")]
+    pub import: Option<String>,
 }
 
 impl Opts {
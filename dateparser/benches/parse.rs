@@ -1,9 +1,13 @@
+use chrono::prelude::*;
 use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
-use dateparser::parse;
-use lazy_static::lazy_static;
+use dateparser::{
+    datetime::{FormatKind, Parse},
+    parse,
+};
+use once_cell::sync::Lazy;
 
-lazy_static! {
-    static ref SELECTED: Vec<&'static str> = vec![
+static SELECTED: Lazy<Vec<&'static str>> = Lazy::new(|| {
+    vec![
         "1511648546",                    // unix_timestamp
         "2017-11-25T22:34:50Z",          // rfc3339
         "Wed, 02 Jun 2021 06:31:39 GMT", // rfc2822
@@ -29,8 +33,41 @@ lazy_static! {
         "171113 14:14:20",               // mysql_log_timestamp
         "2014年04月08日11时25分18秒",    // chinese_ymd_hms
         "2014年04月08日",                // chinese_ymd
-    ];
-}
+    ]
+});
+// inputs that only match the families near the end of the dispatch chain
+static LATE_FAMILIES: Lazy<Vec<&'static str>> = Lazy::new(|| {
+    vec![
+        "03/19/2012 10:11:59",        // slash_mdy_hms
+        "2012/03/19 10:11:59",        // slash_ymd_hms
+        "2014年04月08日11时25分18秒", // chinese_ymd_hms
+        "not a datetime string",      // no match
+    ]
+});
+// a workload of mostly non-numeric inputs, all of which go through the unix_timestamp check
+static MIXED: Lazy<Vec<&'static str>> = Lazy::new(|| {
+    vec![
+        "1511648546",              // unix_timestamp
+        "1620021848429",           // unix_timestamp
+        "2021-04-30 21:14:10",     // ymd_hms
+        "2017-11-25 13:31:15 PST", // ymd_hms_z
+        "4:00pm",                  // hms
+        "May 25, 2021",            // month_mdy
+        "03/19/2012 10:11:59",     // slash_mdy_hms
+        "171113 14:14:20",         // mysql_log_timestamp
+        "not a datetime string",   // no match
+    ]
+});
+// a workload dominated by a single format near the end of the default order
+static MOSTLY_MYSQL_LOG: Lazy<Vec<&'static str>> = Lazy::new(|| {
+    vec![
+        "171113 14:14:20",     // mysql_log_timestamp
+        "171113 14:15:20",     // mysql_log_timestamp
+        "171113 14:16:20",     // mysql_log_timestamp
+        "171113 14:17:20",     // mysql_log_timestamp
+        "2021-04-30 21:14:10", // ymd_hms
+    ]
+});
 
 fn bench_parse_all(c: &mut Criterion) {
     c.bench_with_input(
@@ -39,7 +76,7 @@ fn bench_parse_all(c: &mut Criterion) {
         |b, all| {
             b.iter(|| {
                 for date_str in all.iter() {
-                    let _ = parse(*date_str);
+                    let _ = parse(date_str);
                 }
             })
         },
@@ -54,5 +91,68 @@ fn bench_parse_each(c: &mut Criterion) {
     group.finish();
 }
 
-criterion_group!(benches, bench_parse_all, bench_parse_each);
+fn bench_parse_family_dispatch(c: &mut Criterion) {
+    c.bench_with_input(
+        BenchmarkId::new("parse_family_dispatch", "late_families"),
+        &LATE_FAMILIES,
+        |b, all| {
+            b.iter(|| {
+                for date_str in all.iter() {
+                    let _ = parse(date_str);
+                }
+            })
+        },
+    );
+}
+
+fn bench_parse_mixed(c: &mut Criterion) {
+    c.bench_with_input(
+        BenchmarkId::new("parse_mixed", "mostly_non_numeric"),
+        &MIXED,
+        |b, all| {
+            b.iter(|| {
+                for date_str in all.iter() {
+                    let _ = parse(date_str);
+                }
+            })
+        },
+    );
+}
+
+fn bench_parse_month_mdy(c: &mut Criterion) {
+    let mut group = c.benchmark_group("parse_month_mdy");
+    for date_str in ["May 8, 2009 5:57:51 PM", "May 02, 2021 15:51 UTC"].iter() {
+        group.bench_with_input(*date_str, *date_str, |b, input| b.iter(|| parse(input)));
+    }
+    group.finish();
+}
+
+fn bench_parse_format_order(c: &mut Criterion) {
+    let mut group = c.benchmark_group("parse_format_order");
+    let default_order = Parse::new(&Utc, None);
+    let mysql_log_first = Parse::new(&Utc, None).format_order(&[FormatKind::MysqlLogTimestamp]);
+    for (name, parser) in [
+        ("default", &default_order),
+        ("mysql_log_first", &mysql_log_first),
+    ] {
+        group.bench_with_input(name, &MOSTLY_MYSQL_LOG, |b, all| {
+            b.iter(|| {
+                for date_str in all.iter() {
+                    let _ = parser.parse(date_str);
+                }
+            })
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    bench_parse_all,
+    bench_parse_each,
+    bench_parse_family_dispatch,
+    bench_parse_mixed,
+    bench_parse_month_mdy,
+    bench_parse_format_order
+);
 criterion_main!(benches);
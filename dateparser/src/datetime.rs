@@ -1,14 +1,532 @@
 #![allow(deprecated)]
 use crate::timezone;
 use anyhow::{anyhow, Result};
-use chrono::prelude::*;
-use lazy_static::lazy_static;
-use regex::Regex;
+use chrono::{offset::LocalResult, prelude::*};
+use once_cell::sync::Lazy;
+use regex::{Captures, Regex, RegexSet};
+use std::{
+    borrow::Cow,
+    fmt,
+    ops::{Range, RangeInclusive},
+};
+
+// Indexes of the family pre-filters compiled into `FAMILIES`.
+const YMD_FAMILY: usize = 0;
+const HMS_FAMILY: usize = 1;
+const MONTH_MDY_FAMILY: usize = 2;
+const MONTH_DMY_FAMILY: usize = 3;
+const SLASH_MDY_FAMILY: usize = 4;
+const SLASH_YMD_FAMILY: usize = 5;
+const CJK_YMD_FAMILY: usize = 6;
+
+// Number of digits of a unix timestamp in seconds, milliseconds, microseconds and nanoseconds.
+const UNIT_DIGITS: [usize; 4] = [10, 13, 16, 19];
+
+// Years a unix timestamp of any other length is expected to fall into.
+const PLAUSIBLE_YEARS: RangeInclusive<i32> = 1970..=2100;
+
+// Portuguese month names, along with the English ones that chrono reads.
+const PORTUGUESE_MONTHS: [(&str, &str); 12] = [
+    ("janeiro", "January"),
+    ("fevereiro", "February"),
+    ("março", "March"),
+    ("abril", "April"),
+    ("maio", "May"),
+    ("junho", "June"),
+    ("julho", "July"),
+    ("agosto", "August"),
+    ("setembro", "September"),
+    ("outubro", "October"),
+    ("novembro", "November"),
+    ("dezembro", "December"),
+];
+
+// Formats the date before a business day shorthand, e.g. `2021-05-01` in `2021-05-01 EOD`, may
+// be written in. They only have a date, so that the shorthand sets the time, and they leave out
+// `FormatKind::BusinessDay` itself.
+const BUSINESS_DAY_DATES: [FormatKind; 11] = [
+    FormatKind::Ymd,
+    FormatKind::YyMmDd,
+    FormatKind::BasicIsoDate,
+    FormatKind::MonthYmd,
+    FormatKind::MonthMdy,
+    FormatKind::MonthDmy,
+    FormatKind::SlashMdy,
+    FormatKind::SlashYmd,
+    FormatKind::DotMdyOrYmd,
+    FormatKind::ChineseYmd,
+    FormatKind::KoreanYmd,
+];
+
+// Prefixes of the families, in the order of their indexes.
+const FAMILY_PREFIXES: [&str; 7] = [
+    r"[0-9]{4}-[0-9]{2}",
+    r"[0-9]{1,2}:[0-9]{2}",
+    r"[a-zA-Z]{3,9}\.?\s+[0-9]{1,2}",
+    r"[0-9]{1,2}\s+[a-zA-Z]{3,9}",
+    r"[0-9]{1,2}/[0-9]{1,2}",
+    r"[0-9]{4}/[0-9]{1,2}",
+    r"[0-9]{4}\s*[年년]\s*[0-9]{1,2}\s*[月월]",
+];
+
+// All family pre-filters are tested in a single pass, so that `Parse::parse` only falls into
+// the families whose prefix matched the input.
+static FAMILIES: Lazy<RegexSet> = Lazy::new(|| {
+    RegexSet::new(FAMILY_PREFIXES.iter().map(|prefix| format!("^{}", prefix))).unwrap()
+});
+// The same prefixes, unanchored, find where a datetime may start inside of a longer text,
+// along with a leading weekday, e.g. `Wed, 02 Jun 2021 06:31:39 GMT`, a relative weekday, e.g.
+// `last mon`, or a business day shorthand on its own, e.g. `EOD`.
+static FAMILIES_IN_TEXT: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(&format!(
+        r"\b({}|(?i:mon|tue|wed|thu|fri|sat|sun)[a-z]*,?\s|(?i:next|last|this)\s|(?i:eod|bod|cob)\b)",
+        FAMILY_PREFIXES.join("|")
+    ))
+    .unwrap()
+});
+
+/// ParseError is returned, wrapped in an [`anyhow::Error`], when the input is empty, or when it
+/// matches an accepted format, but the parsed values do not make up a valid datetime. Use
+/// `downcast_ref` to tell it apart from the generic error for an input that did not match any
+/// formats.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ParseError {
+    /// The local datetime does not exist in the parsing timezone, e.g. it falls into the hour
+    /// skipped when daylight saving time starts.
+    NonexistentLocalTime(NaiveDateTime),
+    /// The local datetime occurs twice in the parsing timezone, e.g. it falls into the hour
+    /// repeated when daylight saving time ends, and [`DstPolicy::Error`] is in use.
+    AmbiguousLocalTime(NaiveDateTime),
+    /// The input is the all-zeros date, such as `0000-00-00 00:00:00`, which MySQL uses to store
+    /// "no date".
+    ZeroDate,
+    /// The input matches a `yyyy-mm-dd` format, but the date does not exist, e.g. `2021-02-30`
+    /// or `2021-13-01`. It holds the offending date.
+    InvalidDate(String),
+    /// The input matches the `hh:mm:ss` format, but the hour is over 23, the minute is over 59 or
+    /// the second is over 60, e.g. `25:00` or `12:60`. It holds the offending time.
+    InvalidTime(String),
+    /// The input is empty or only has whitespace, so there is nothing to parse.
+    EmptyInput,
+    /// The parsed year falls outside of the bounds set by [`Parse::year_bounds`]. It holds the
+    /// year.
+    YearOutOfBounds(i32),
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NonexistentLocalTime(datetime) => {
+                write!(f, "{} does not exist in the parsing timezone.", datetime)
+            }
+            Self::AmbiguousLocalTime(datetime) => {
+                write!(f, "{} is ambiguous in the parsing timezone.", datetime)
+            }
+            Self::ZeroDate => write!(f, "all-zeros date is not a valid date."),
+            Self::InvalidDate(date) => write!(f, "{} is not a valid date.", date),
+            Self::InvalidTime(time) => write!(f, "{} is not a valid time.", time),
+            Self::EmptyInput => write!(f, "input is empty."),
+            Self::YearOutOfBounds(year) => write!(f, "year {} is out of bounds.", year),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// DstPolicy decides which datetime to use when a local datetime without offset occurs twice in
+/// the parsing timezone, e.g. `2021-11-07 01:30` in `America/New_York` when daylight saving time
+/// ends. The default is [`DstPolicy::Earliest`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum DstPolicy {
+    /// Use the earlier of the two datetimes, which is the one still in daylight saving time.
+    #[default]
+    Earliest,
+    /// Use the later of the two datetimes, which is the one after daylight saving time ends.
+    Latest,
+    /// Return [`ParseError::AmbiguousLocalTime`].
+    Error,
+}
+
+/// DateOnlyPolicy decides the time of a date-only input, such as `2021-02-21` or `May 25, 2021`,
+/// when [`Parse`] is not given a default time. The default is [`DateOnlyPolicy::CurrentTime`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum DateOnlyPolicy {
+    /// Use the current time in the parsing timezone.
+    #[default]
+    CurrentTime,
+    /// Use the start of the day, 00:00:00.
+    StartOfDay,
+}
+
+/// DateOrder decides how the month and the day of a numeric date, which both take one or two
+/// digits, are read when the year comes first, e.g. `2014/8/4` or `21-02-21`. The default is
+/// [`DateOrder::Ymd`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum DateOrder {
+    /// Read the month before the day, e.g. `2014/8/4` is August 4, 2014.
+    #[default]
+    Ymd,
+    /// Read the day before the month, e.g. `2014/8/4` is April 8, 2014, as in the locales that
+    /// write `yyyy/dd/mm`.
+    Ydm,
+}
+
+/// FormatKind identifies one of the accepted formats, in the same names used by the parser for
+/// each of them, e.g. [`FormatKind::YmdHms`] for `ymd_hms`. It is used with
+/// [`Parse::format_order`] to choose which formats are tried first, and with
+/// [`Parse::allowed_formats`] to choose which formats are tried at all.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum FormatKind {
+    /// `custom`, the formats given to [`Parse::custom_formats`] and
+    /// [`Parse::custom_date_formats`], e.g. `%d|%m|%Y` for `08|05|2021`
+    Custom,
+    /// `unix_timestamp`, e.g. `1511648546`
+    UnixTimestamp,
+    /// `unix_timestamp_unit`, e.g. `1620021848429ms`
+    UnixTimestampUnit,
+    /// `rfc2822`, e.g. `Wed, 02 Jun 2021 06:31:39 GMT`
+    Rfc2822,
+    /// `cookie_date`, e.g. `Wed, 09-Jun-2021 10:18:14 GMT`
+    CookieDate,
+    /// `rfc850`, e.g. `Monday, 02-Jan-06 15:04:05 GMT`
+    Rfc850,
+    /// `ansi_c`, e.g. `Wed Jun  2 06:31:39 2021`
+    AnsiC,
+    /// `unix_date`, e.g. `Wed Jun  2 06:31:39 PDT 2021`
+    UnixDate,
+    /// `js_date_string`, e.g. `Wed Jun 02 2021 06:31:39 GMT-0700`
+    JsDateString,
+    /// `business_day`, e.g. `2021-05-01 EOD`
+    BusinessDay,
+    /// `zero_date`, e.g. `0000-00-00 00:00:00`
+    ZeroDate,
+    /// `rfc3339`, e.g. `2017-11-25T22:34:50Z`
+    Rfc3339,
+    /// `postgres_timestamp`, e.g. `2019-11-29 08:08:05-08`
+    PostgresTimestamp,
+    /// `go_time_string`, e.g. `2021-05-01 10:00:00.123456789 +0000 UTC`
+    GoTimeString,
+    /// `ymd_hms`, e.g. `2021-04-30 21:14:10`
+    YmdHms,
+    /// `ymd_hms_z`, e.g. `2017-11-25 13:31:15 PST`
+    YmdHmsZ,
+    /// `ymd`, e.g. `2021-02-21`
+    Ymd,
+    /// `ymd_z`, e.g. `2021-02-21 PST`
+    YmdZ,
+    /// `negative_ymd`, e.g. `-0044-03-15`
+    NegativeYmd,
+    /// `yy_mm_dd`, e.g. `21-02-21`
+    YyMmDd,
+    /// `basic_iso_datetime`, e.g. `20210501T101112`
+    BasicIsoDatetime,
+    /// `basic_iso_date`, e.g. `20210501`
+    BasicIsoDate,
+    /// `hms`, e.g. `4:00pm`
+    Hms,
+    /// `hms_z`, e.g. `6:00 AM PST`
+    HmsZ,
+    /// `hour_meridiem`, e.g. `11AM`
+    HourMeridiem,
+    /// `compact_hm`, e.g. `1430`
+    CompactHm,
+    /// `month_ymd`, e.g. `2021-Feb-21`
+    MonthYmd,
+    /// `quarter`, e.g. `Q2 2021`
+    Quarter,
+    /// `year_month`, e.g. `2021-05`
+    YearMonth,
+    /// `era_year`, e.g. `44 BC`
+    EraYear,
+    /// `week_of_year`, e.g. `Week 23 of 2021`
+    WeekOfYear,
+    /// `relative_weekday`, e.g. `next Friday`
+    RelativeWeekday,
+    /// `iso_duration`, e.g. `-PT2H`
+    IsoDuration,
+    /// `month_md_hms`, e.g. `May 27 02:45:27`
+    MonthMdHms,
+    /// `month_mdy_hms`, e.g. `May 8, 2009 5:57:51 PM`
+    MonthMdyHms,
+    /// `month_mdy_hms_z`, e.g. `May 02, 2021 15:51 UTC`
+    MonthMdyHmsZ,
+    /// `month_mdy`, e.g. `May 25, 2021`
+    MonthMdy,
+    /// `month_dmy_hms`, e.g. `14 May 2019 19:11:40.164`
+    MonthDmyHms,
+    /// `month_dmy`, e.g. `1 July 2013`
+    MonthDmy,
+    /// `slash_mdy_hms`, e.g. `03/19/2012 10:11:59`
+    SlashMdyHms,
+    /// `slash_mdy`, e.g. `08/21/71`
+    SlashMdy,
+    /// `slash_ymd_hms`, e.g. `2012/03/19 10:11:59`
+    SlashYmdHms,
+    /// `slash_ymd`, e.g. `2014/3/31`
+    SlashYmd,
+    /// `dot_mdy_or_ymd`, e.g. `2014.03.30`
+    DotMdyOrYmd,
+    /// `mysql_log_timestamp`, e.g. `171113 14:14:20`
+    MysqlLogTimestamp,
+    /// `chinese_ymd_hms`, e.g. `2014年04月08日11时25分18秒`
+    ChineseYmdHms,
+    /// `chinese_ymd_meridiem_hms`, e.g. `2014年04月08日下午3时25分`
+    ChineseYmdMeridiemHms,
+    /// `chinese_ymd`, e.g. `2014年04月08日`
+    ChineseYmd,
+    /// `korean_ymd_hms`, e.g. `2014년04월08일 11시25분18초`
+    KoreanYmdHms,
+    /// `korean_ymd`, e.g. `2014년04월08일`
+    KoreanYmd,
+}
+
+impl FormatKind {
+    /// All accepted formats, in the default order they are tried by [`Parse::parse`].
+    pub const ALL: [FormatKind; 50] = [
+        FormatKind::Custom,
+        FormatKind::UnixTimestamp,
+        FormatKind::UnixTimestampUnit,
+        FormatKind::Rfc2822,
+        FormatKind::CookieDate,
+        FormatKind::Rfc850,
+        FormatKind::AnsiC,
+        FormatKind::UnixDate,
+        FormatKind::JsDateString,
+        FormatKind::BusinessDay,
+        FormatKind::ZeroDate,
+        FormatKind::Rfc3339,
+        FormatKind::PostgresTimestamp,
+        FormatKind::GoTimeString,
+        FormatKind::YmdHms,
+        FormatKind::YmdHmsZ,
+        FormatKind::Ymd,
+        FormatKind::YmdZ,
+        FormatKind::NegativeYmd,
+        FormatKind::YyMmDd,
+        FormatKind::BasicIsoDatetime,
+        FormatKind::BasicIsoDate,
+        FormatKind::Hms,
+        FormatKind::HmsZ,
+        FormatKind::HourMeridiem,
+        FormatKind::CompactHm,
+        FormatKind::MonthYmd,
+        FormatKind::Quarter,
+        FormatKind::YearMonth,
+        FormatKind::EraYear,
+        FormatKind::WeekOfYear,
+        FormatKind::RelativeWeekday,
+        FormatKind::IsoDuration,
+        FormatKind::MonthMdHms,
+        FormatKind::MonthMdyHms,
+        FormatKind::MonthMdyHmsZ,
+        FormatKind::MonthMdy,
+        FormatKind::MonthDmyHms,
+        FormatKind::MonthDmy,
+        FormatKind::SlashMdyHms,
+        FormatKind::SlashMdy,
+        FormatKind::SlashYmdHms,
+        FormatKind::SlashYmd,
+        FormatKind::DotMdyOrYmd,
+        FormatKind::MysqlLogTimestamp,
+        FormatKind::ChineseYmdHms,
+        FormatKind::ChineseYmdMeridiemHms,
+        FormatKind::ChineseYmd,
+        FormatKind::KoreanYmdHms,
+        FormatKind::KoreanYmd,
+    ];
+
+    /// Name of the format, which is also the name of the parser for it, e.g. `ymd_hms`.
+    pub fn name(self) -> &'static str {
+        match self {
+            FormatKind::Custom => "custom",
+            FormatKind::UnixTimestamp => "unix_timestamp",
+            FormatKind::UnixTimestampUnit => "unix_timestamp_unit",
+            FormatKind::Rfc2822 => "rfc2822",
+            FormatKind::CookieDate => "cookie_date",
+            FormatKind::Rfc850 => "rfc850",
+            FormatKind::AnsiC => "ansi_c",
+            FormatKind::UnixDate => "unix_date",
+            FormatKind::JsDateString => "js_date_string",
+            FormatKind::BusinessDay => "business_day",
+            FormatKind::ZeroDate => "zero_date",
+            FormatKind::Rfc3339 => "rfc3339",
+            FormatKind::PostgresTimestamp => "postgres_timestamp",
+            FormatKind::GoTimeString => "go_time_string",
+            FormatKind::YmdHms => "ymd_hms",
+            FormatKind::YmdHmsZ => "ymd_hms_z",
+            FormatKind::Ymd => "ymd",
+            FormatKind::YmdZ => "ymd_z",
+            FormatKind::NegativeYmd => "negative_ymd",
+            FormatKind::YyMmDd => "yy_mm_dd",
+            FormatKind::BasicIsoDatetime => "basic_iso_datetime",
+            FormatKind::BasicIsoDate => "basic_iso_date",
+            FormatKind::Hms => "hms",
+            FormatKind::HmsZ => "hms_z",
+            FormatKind::HourMeridiem => "hour_meridiem",
+            FormatKind::CompactHm => "compact_hm",
+            FormatKind::MonthYmd => "month_ymd",
+            FormatKind::Quarter => "quarter",
+            FormatKind::YearMonth => "year_month",
+            FormatKind::EraYear => "era_year",
+            FormatKind::WeekOfYear => "week_of_year",
+            FormatKind::RelativeWeekday => "relative_weekday",
+            FormatKind::IsoDuration => "iso_duration",
+            FormatKind::MonthMdHms => "month_md_hms",
+            FormatKind::MonthMdyHms => "month_mdy_hms",
+            FormatKind::MonthMdyHmsZ => "month_mdy_hms_z",
+            FormatKind::MonthMdy => "month_mdy",
+            FormatKind::MonthDmyHms => "month_dmy_hms",
+            FormatKind::MonthDmy => "month_dmy",
+            FormatKind::SlashMdyHms => "slash_mdy_hms",
+            FormatKind::SlashMdy => "slash_mdy",
+            FormatKind::SlashYmdHms => "slash_ymd_hms",
+            FormatKind::SlashYmd => "slash_ymd",
+            FormatKind::DotMdyOrYmd => "dot_mdy_or_ymd",
+            FormatKind::MysqlLogTimestamp => "mysql_log_timestamp",
+            FormatKind::ChineseYmdHms => "chinese_ymd_hms",
+            FormatKind::ChineseYmdMeridiemHms => "chinese_ymd_meridiem_hms",
+            FormatKind::ChineseYmd => "chinese_ymd",
+            FormatKind::KoreanYmdHms => "korean_ymd_hms",
+            FormatKind::KoreanYmd => "korean_ymd",
+        }
+    }
+
+    // Words, in lowercase, that an input in this format may end with, e.g. `eod` in
+    // `2021-05-01 EOD`, apart from time zone names, month and weekday names, and `am` or `pm`,
+    // which any format may end with.
+    fn last_words(self) -> &'static [&'static str] {
+        match self {
+            FormatKind::BusinessDay => &["eod", "bod", "cob"],
+            FormatKind::RelativeWeekday => &["week", "weekend"],
+            FormatKind::EraYear => &["bc", "bce"],
+            _ => &[],
+        }
+    }
+
+    // Index of the family pre-filter in `FAMILIES` that has to match before this format is
+    // tried. Formats that are not part of a family are always tried.
+    fn family(self) -> Option<usize> {
+        match self {
+            FormatKind::ZeroDate
+            | FormatKind::Rfc3339
+            | FormatKind::PostgresTimestamp
+            | FormatKind::GoTimeString
+            | FormatKind::YmdHms
+            | FormatKind::YmdHmsZ
+            | FormatKind::Ymd
+            | FormatKind::YmdZ => Some(YMD_FAMILY),
+            FormatKind::Hms | FormatKind::HmsZ => Some(HMS_FAMILY),
+            FormatKind::MonthMdHms
+            | FormatKind::MonthMdyHms
+            | FormatKind::MonthMdyHmsZ
+            | FormatKind::MonthMdy => Some(MONTH_MDY_FAMILY),
+            FormatKind::MonthDmyHms | FormatKind::MonthDmy => Some(MONTH_DMY_FAMILY),
+            FormatKind::SlashMdyHms | FormatKind::SlashMdy => Some(SLASH_MDY_FAMILY),
+            FormatKind::SlashYmdHms | FormatKind::SlashYmd => Some(SLASH_YMD_FAMILY),
+            FormatKind::ChineseYmdHms
+            | FormatKind::ChineseYmdMeridiemHms
+            | FormatKind::ChineseYmd
+            | FormatKind::KoreanYmdHms
+            | FormatKind::KoreanYmd => Some(CJK_YMD_FAMILY),
+            FormatKind::UnixTimestamp
+            | FormatKind::Rfc2822
+            | FormatKind::HourMeridiem
+            | FormatKind::MonthYmd
+            | FormatKind::DotMdyOrYmd
+            | FormatKind::MysqlLogTimestamp
+            | FormatKind::CookieDate
+            | FormatKind::AnsiC
+            | FormatKind::UnixDate
+            | FormatKind::Rfc850
+            | FormatKind::Quarter
+            | FormatKind::YearMonth
+            | FormatKind::WeekOfYear
+            | FormatKind::CompactHm
+            | FormatKind::UnixTimestampUnit
+            | FormatKind::YyMmDd
+            | FormatKind::RelativeWeekday
+            | FormatKind::JsDateString
+            | FormatKind::BusinessDay
+            | FormatKind::Custom
+            | FormatKind::BasicIsoDatetime
+            | FormatKind::BasicIsoDate
+            | FormatKind::IsoDuration
+            | FormatKind::NegativeYmd
+            | FormatKind::EraYear => None,
+        }
+    }
+}
+
+/// ParseStats tells how an input was parsed by [`Parse::parse_instrumented`], to help put the
+/// most common formats of a workload first with [`Parse::format_order`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ParseStats {
+    /// Number of formats tried, including the one that matched. Formats skipped because the
+    /// input does not start like them are not counted.
+    pub attempts: usize,
+    /// The format that matched the input, or `None` when no format did. A format that matched
+    /// but failed to parse, such as `2021-02-30`, is still the one that matched.
+    pub matched: Option<FormatKind>,
+}
 
 /// Parse struct has methods implemented parsers for accepted formats.
-pub struct Parse<'z, Tz2> {
-    tz: &'z Tz2,
+///
+/// A [`Parse`] created by [`Parse::new`] borrows the parsing timezone, which is cheap to set up
+/// for a one-off parse, but ties the parser to the lifetime of the timezone. To store a
+/// configured parser long-term, e.g. in a server's shared state, use [`OwnedParse`] instead.
+#[derive(Clone)]
+pub struct Parse<'z, Tz2>
+where
+    Tz2: TimeZone,
+{
+    tz: Cow<'z, Tz2>,
     default_time: Option<NaiveTime>,
+    epoch_sentinels: Option<(String, String)>,
+    dst_policy: DstPolicy,
+    date_order: DateOrder,
+    date_only_policy: DateOnlyPolicy,
+    allow_12_hour: bool,
+    compact_time: bool,
+    negative_years: bool,
+    week_start: Weekday,
+    cob_hour: u32,
+    custom_formats: Vec<String>,
+    custom_date_formats: Vec<String>,
+    format_order: Cow<'static, [FormatKind]>,
+    year_bounds: Option<RangeInclusive<i32>>,
+    allowed_formats: Option<Vec<FormatKind>>,
+}
+
+/// OwnedParse is a [`Parse`] that holds the parsing timezone by value instead of borrowing it,
+/// so it is `'static` and can be cloned and stored long-term. The trade-off is that the timezone
+/// is moved into, and cloned along with, the parser, which is cheap for `FixedOffset`, `Utc` and
+/// `chrono_tz::Tz`.
+///
+/// ```
+/// use chrono::prelude::*;
+/// use dateparser::datetime::OwnedParse;
+/// use std::error::Error;
+///
+/// fn main() -> Result<(), Box<dyn Error>> {
+///     let parse = OwnedParse::new_owned(FixedOffset::west(8 * 3600), None);
+///     let cloned = parse.clone();
+///     assert_eq!(
+///         cloned.parse("2021-06-05 06:19 PM")?,
+///         Utc.ymd(2021, 6, 6).and_hms(2, 19, 0),
+///     );
+///     Ok(())
+/// }
+/// ```
+pub type OwnedParse<Tz2> = Parse<'static, Tz2>;
+
+impl<Tz2> OwnedParse<Tz2>
+where
+    Tz2: TimeZone,
+{
+    /// Create a new instance of [`OwnedParse`] that takes ownership of the custom parsing timezone.
+    pub fn new_owned(tz: Tz2, default_time: Option<NaiveTime>) -> Self {
+        Self::with_tz(Cow::Owned(tz), default_time)
+    }
 }
 
 impl<'z, Tz2> Parse<'z, Tz2>
@@ -18,145 +536,924 @@ where
     /// Create a new instrance of [`Parse`] with a custom parsing timezone that handles the
     /// datetime string without time offset.
     pub fn new(tz: &'z Tz2, default_time: Option<NaiveTime>) -> Self {
-        Self { tz, default_time }
+        Self::with_tz(Cow::Borrowed(tz), default_time)
+    }
+
+    fn with_tz(tz: Cow<'z, Tz2>, default_time: Option<NaiveTime>) -> Self {
+        Self {
+            tz,
+            default_time,
+            epoch_sentinels: None,
+            dst_policy: DstPolicy::default(),
+            date_order: DateOrder::default(),
+            date_only_policy: DateOnlyPolicy::default(),
+            allow_12_hour: true,
+            compact_time: false,
+            negative_years: false,
+            week_start: Weekday::Mon,
+            cob_hour: 17,
+            custom_formats: Vec::new(),
+            custom_date_formats: Vec::new(),
+            format_order: Cow::Borrowed(&FormatKind::ALL),
+            year_bounds: None,
+            allowed_formats: None,
+        }
+    }
+
+    /// The parsing timezone, which a datetime string without time offset is read in.
+    pub fn timezone(&self) -> &Tz2 {
+        &self.tz
+    }
+
+    /// The time used for a date-only input, or `None` when it is picked by the
+    /// [`DateOnlyPolicy`].
+    pub fn default_time(&self) -> Option<NaiveTime> {
+        self.default_time
+    }
+
+    /// Set the [`DstPolicy`] used to resolve a local datetime that occurs twice in the parsing
+    /// timezone.
+    pub fn dst_policy(mut self, dst_policy: DstPolicy) -> Self {
+        self.dst_policy = dst_policy;
+        self
+    }
+
+    /// Set the [`DateOrder`] used to read the month and the day of a slash separated date with the
+    /// year first, such as `2014/8/4` and `2014/8/4 22:05`, and of a dash separated date with a two
+    /// digit year, such as `21-02-21`.
+    ///
+    /// ```
+    /// use chrono::prelude::*;
+    /// use dateparser::datetime::{DateOrder, Parse};
+    ///
+    /// let parse = Parse::new(&Utc, Some(NaiveTime::from_hms(0, 0, 0))).date_order(DateOrder::Ydm);
+    /// assert_eq!(
+    ///     parse.parse("2014/8/4").unwrap(),
+    ///     Utc.ymd(2014, 4, 8).and_hms(0, 0, 0),
+    /// );
+    /// ```
+    pub fn date_order(mut self, date_order: DateOrder) -> Self {
+        self.date_order = date_order;
+        self
+    }
+
+    /// Set the [`DateOnlyPolicy`] used to pick the time of a date-only input. It only applies when
+    /// no default time is given to [`Parse::new`], which otherwise always wins, so the start of the
+    /// day can be asked for without choosing a time in a particular timezone.
+    ///
+    /// ```
+    /// use chrono::prelude::*;
+    /// use dateparser::datetime::{DateOnlyPolicy, Parse};
+    ///
+    /// let parse = Parse::new(&Utc, None).date_only_policy(DateOnlyPolicy::StartOfDay);
+    /// assert_eq!(
+    ///     parse.parse("2021-02-21").unwrap(),
+    ///     Utc.ymd(2021, 2, 21).and_hms(0, 0, 0),
+    /// );
+    /// ```
+    pub fn date_only_policy(mut self, date_only_policy: DateOnlyPolicy) -> Self {
+        self.date_only_policy = date_only_policy;
+        self
+    }
+
+    /// Strip a pair of prefix and suffix sentinels wrapping an epoch value, such as
+    /// `@@1511648546@@`, before parsing it as a unix timestamp. By default, no sentinels are
+    /// stripped.
+    pub fn epoch_sentinels(mut self, prefix: &str, suffix: &str) -> Self {
+        self.epoch_sentinels = Some((prefix.to_string(), suffix.to_string()));
+        self
+    }
+
+    /// Allow or skip the 12-hour formats with an am/pm marker, such as `2014-04-26 05:24:37 PM`,
+    /// `4:00pm`, `11AM` and `2014年04月08日下午3时`. It is allowed by default, and can be turned off for
+    /// inputs that only come in 24-hour time, so that those format attempts are skipped and a
+    /// malformed am/pm datetime is not accepted. The affected formats are `yyyy-mm-dd hh:mm:ss`,
+    /// `yyyy-mm-dd hh:mm:ss z`, `hh:mm:ss`, `hh:mm:ss z`, `hh am/pm`, `Mon dd hh:mm:ss`,
+    /// `Mon dd, yyyy, hh:mm:ss`, `Mon dd, yyyy hh:mm:ss z`, `dd Mon yyyy hh:mm:ss`,
+    /// `mm/dd/yyyy hh:mm:ss`, `yyyy/mm/dd hh:mm:ss` and the chinese
+    /// `yyyy年mm月dd日 上午/下午 hh时mm分ss秒`.
+    pub fn allow_12_hour(mut self, allow_12_hour: bool) -> Self {
+        self.allow_12_hour = allow_12_hour;
+        self
+    }
+
+    /// Accept a 24-hour time written as four digits without a colon, such as `1430` for 14:30,
+    /// on the current date. It is off by default, because such an input is just as likely to be
+    /// a plain number.
+    pub fn compact_time(mut self, compact_time: bool) -> Self {
+        self.compact_time = compact_time;
+        self
+    }
+
+    /// Accept a year before year 1, written as an ISO 8601 negative year, such as `-0044-03-15`,
+    /// or as a year-only `44 BC` or `44 BCE`, for historical data. Years are counted as in
+    /// chrono and ISO 8601, where 1 BC is year 0, so `44 BC` is year -43. It is off by default.
+    ///
+    /// ```
+    /// use chrono::prelude::*;
+    /// use dateparser::datetime::Parse;
+    ///
+    /// let parse = Parse::new(&Utc, Some(NaiveTime::from_hms(0, 0, 0))).negative_years(true);
+    /// assert_eq!(
+    ///     parse.parse("-0044-03-15").unwrap(),
+    ///     Utc.ymd(-44, 3, 15).and_hms(0, 0, 0),
+    /// );
+    /// assert_eq!(parse.parse("44 BC").unwrap(), Utc.ymd(-43, 1, 1).and_hms(0, 0, 0));
+    /// ```
+    pub fn negative_years(mut self, negative_years: bool) -> Self {
+        self.negative_years = negative_years;
+        self
+    }
+
+    /// Set the first day of a week, which `this week`, `last week` and `next week` are read as,
+    /// and which `this <weekday>` and `this weekend`, the Saturday of a week, are counted from. It is
+    /// Monday by default.
+    ///
+    /// ```
+    /// use chrono::prelude::*;
+    /// use dateparser::datetime::Parse;
+    ///
+    /// let parse = Parse::new(&Utc, None).week_start(Weekday::Sun);
+    /// assert_eq!(parse.parse("this week").unwrap().weekday(), Weekday::Sun);
+    /// ```
+    pub fn week_start(mut self, week_start: Weekday) -> Self {
+        self.week_start = week_start;
+        self
+    }
+
+    /// Set the hour of the day that `COB`, close of business, is read as, such as in
+    /// `2021-05-01 COB`. It is 17, i.e. 5 PM, by default.
+    ///
+    /// # Panics
+    ///
+    /// Panics when the hour is not from 0 to 23.
+    ///
+    /// ```
+    /// use chrono::prelude::*;
+    /// use dateparser::datetime::Parse;
+    ///
+    /// let parse = Parse::new(&Utc, None).cob_hour(18);
+    /// assert_eq!(
+    ///     parse.parse("2021-05-01 COB").unwrap(),
+    ///     Utc.ymd(2021, 5, 1).and_hms(18, 0, 0),
+    /// );
+    /// ```
+    pub fn cob_hour(mut self, cob_hour: u32) -> Self {
+        assert!(cob_hour < 24, "{} is not a valid hour.", cob_hour);
+        self.cob_hour = cob_hour;
+        self
+    }
+
+    /// Try the given chrono strftime formats, such as `%d|%m|%Y %H:%M`, before any accepted
+    /// format, for in-house formats that this crate does not know. A format with an offset, `%z`,
+    /// is read at that offset, and one without is read in the parsing timezone. They are tried as
+    /// [`FormatKind::Custom`], which [`Parse::format_order`] moves like any other format, on the
+    /// input cleaned up the same way as for the accepted formats, e.g. with runs of whitespace
+    /// written as a single space.
+    ///
+    /// ```
+    /// use chrono::prelude::*;
+    /// use dateparser::datetime::Parse;
+    ///
+    /// let parse = Parse::new(&Utc, None).custom_formats(&["%d|%m|%Y %H:%M"]);
+    /// assert_eq!(
+    ///     parse.parse("08|05|2021 10:30").unwrap(),
+    ///     Utc.ymd(2021, 5, 8).and_hms(10, 30, 0),
+    /// );
+    /// ```
+    pub fn custom_formats(mut self, formats: &[&str]) -> Self {
+        self.custom_formats = formats.iter().map(|format| format.to_string()).collect();
+        self
+    }
+
+    /// Same as [`Parse::custom_formats`], but for formats with only a date, such as `%d|%m|%Y`.
+    /// They are tried after the formats with a time, and their time is picked the same way as
+    /// for any other date-only input, from the default time or the [`DateOnlyPolicy`].
+    ///
+    /// ```
+    /// use chrono::prelude::*;
+    /// use dateparser::datetime::Parse;
+    ///
+    /// let parse = Parse::new(&Utc, Some(NaiveTime::from_hms(0, 0, 0)))
+    ///     .custom_date_formats(&["%d|%m|%Y"]);
+    /// assert_eq!(
+    ///     parse.parse("08|05|2021").unwrap(),
+    ///     Utc.ymd(2021, 5, 8).and_hms(0, 0, 0),
+    /// );
+    /// ```
+    pub fn custom_date_formats(mut self, formats: &[&str]) -> Self {
+        self.custom_date_formats = formats.iter().map(|format| format.to_string()).collect();
+        self
+    }
+
+    /// Try the given formats first, in the given order, followed by the rest of the accepted
+    /// formats in their default order. Putting the most common formats of a workload first makes
+    /// a match quicker on average. When an input matches more than one format, the one tried
+    /// first wins.
+    ///
+    /// ```
+    /// use chrono::prelude::*;
+    /// use dateparser::datetime::{FormatKind, Parse};
+    ///
+    /// let parse = Parse::new(&Utc, None)
+    ///     .format_order(&[FormatKind::SlashMdyHms, FormatKind::MysqlLogTimestamp]);
+    /// assert_eq!(
+    ///     parse.parse("03/19/2012 10:11:59").unwrap(),
+    ///     Utc.ymd(2012, 3, 19).and_hms(10, 11, 59),
+    /// );
+    /// ```
+    pub fn format_order(mut self, formats: &[FormatKind]) -> Self {
+        let mut format_order = Vec::with_capacity(FormatKind::ALL.len());
+        for &kind in formats.iter().chain(FormatKind::ALL.iter()) {
+            if !format_order.contains(&kind) && self.is_allowed(kind) {
+                format_order.push(kind);
+            }
+        }
+        self.format_order = Cow::Owned(format_order);
+        self
+    }
+
+    /// Only try the given formats, so that an input in any other accepted format fails to parse,
+    /// e.g. to accept unambiguous ISO 8601 and unix timestamps from an untrusted source, while
+    /// rejecting slash dates that may be read either way. The formats are still tried in the
+    /// order set by [`Parse::format_order`]. [`FormatKind::Custom`] has to be allowed for the
+    /// formats given to [`Parse::custom_formats`] to be tried.
+    ///
+    /// ```
+    /// use chrono::prelude::*;
+    /// use dateparser::datetime::{FormatKind, Parse};
+    ///
+    /// let parse = Parse::new(&Utc, None)
+    ///     .allowed_formats(&[FormatKind::Rfc3339, FormatKind::UnixTimestamp]);
+    /// assert_eq!(
+    ///     parse.parse("2021-05-01T01:17:02Z").unwrap(),
+    ///     Utc.ymd(2021, 5, 1).and_hms(1, 17, 2),
+    /// );
+    /// assert!(parse.parse("05/01/2021").is_err());
+    /// ```
+    pub fn allowed_formats(mut self, formats: &[FormatKind]) -> Self {
+        self.allowed_formats = Some(formats.to_vec());
+        let format_order = self
+            .format_order
+            .iter()
+            .copied()
+            .filter(|&kind| self.is_allowed(kind))
+            .collect();
+        self.format_order = Cow::Owned(format_order);
+        self
+    }
+
+    // Tell whether a format is left to be tried by `allowed_formats`.
+    fn is_allowed(&self, kind: FormatKind) -> bool {
+        match &self.allowed_formats {
+            Some(allowed) => allowed.contains(&kind),
+            None => true,
+        }
+    }
+
+    /// Reject a parsed datetime whose year, in the parsing timezone, falls outside of the given
+    /// bounds with [`ParseError::YearOutOfBounds`], e.g. `0000-01-01` or a year 99999 read from
+    /// malformed data. Any year chrono supports is accepted by default.
+    ///
+    /// ```
+    /// use chrono::prelude::*;
+    /// use dateparser::datetime::{Parse, ParseError};
+    ///
+    /// let parse = Parse::new(&Utc, None).year_bounds(1900..=2100);
+    /// assert!(parse.parse("2021-05-01 10:00:00").is_ok());
+    /// let err = parse.parse("1000-01-01 10:00:00").unwrap_err();
+    /// assert_eq!(err.downcast_ref(), Some(&ParseError::YearOutOfBounds(1000)));
+    /// ```
+    pub fn year_bounds(mut self, bounds: RangeInclusive<i32>) -> Self {
+        self.year_bounds = Some(bounds);
+        self
     }
 
     /// This method tries to parse the input datetime string with a list of accepted formats. See
     /// more exmaples from [`Parse`], [`crate::parse()`] and [`crate::parse_with_timezone()`].
+    ///
+    /// An empty or whitespace-only input fails right away with [`ParseError::EmptyInput`].
+    ///
+    /// A leap second, such as `2016-12-31 23:59:60 UTC`, is kept in chrono's leap second
+    /// representation, i.e. as second 59 with a fraction of one second or more, rather than
+    /// clamped or rolled over to the next minute.
     pub fn parse(&self, input: &str) -> Result<DateTime<Utc>> {
-        self.unix_timestamp(input)
-            .or_else(|| self.rfc2822(input))
-            .or_else(|| self.ymd_family(input))
-            .or_else(|| self.hms_family(input))
-            .or_else(|| self.month_ymd(input))
-            .or_else(|| self.month_mdy_family(input))
-            .or_else(|| self.month_dmy_family(input))
-            .or_else(|| self.slash_mdy_family(input))
-            .or_else(|| self.slash_ymd_family(input))
-            .or_else(|| self.dot_mdy_or_ymd(input))
-            .or_else(|| self.mysql_log_timestamp(input))
-            .or_else(|| self.chinese_ymd_family(input))
+        self.parse_at(input, &Utc::now())
+    }
+
+    // Same as `parse`, but with the current time given, so that a batch of inputs can share it.
+    fn parse_at(&self, input: &str, now: &DateTime<Utc>) -> Result<DateTime<Utc>> {
+        if input.trim().is_empty() {
+            return Err(ParseError::EmptyInput.into());
+        }
+        self.parse_matched(input, now)
             .unwrap_or_else(|| Err(anyhow!("{} did not match any formats.", input)))
     }
 
-    fn ymd_family(&self, input: &str) -> Option<Result<DateTime<Utc>>> {
-        lazy_static! {
-            static ref RE: Regex = Regex::new(r"^[0-9]{4}-[0-9]{2}").unwrap();
+    /// Similar to [`Parse::parse`], but returns `None` instead of an error when the input cannot be
+    /// parsed, without building an error message for it. It suits a hot loop that only needs the
+    /// datetime when there is one.
+    pub fn try_parse(&self, input: &str) -> Option<DateTime<Utc>> {
+        self.parse_matched(input, &Utc::now())?.ok()
+    }
+
+    /// Tell whether the input is written in one of the accepted formats. An input in an accepted
+    /// format that is still not a valid datetime, such as `2021-05-01 10:00:00 +2500` with an out
+    /// of range offset or `2021-02-30`, is a date, and [`Parse::parse`] returns what is wrong with
+    /// it, while [`Parse::try_parse`] returns `None`.
+    ///
+    /// ```
+    /// use chrono::prelude::*;
+    /// use dateparser::datetime::Parse;
+    ///
+    /// let parse = Parse::new(&Utc, None);
+    /// assert!(parse.is_date("2021-05-01 10:00:00 +0800"));
+    /// assert!(parse.is_date("2021-05-01 10:00:00 +2500"));
+    /// assert!(!parse.is_date("not a date"));
+    /// ```
+    pub fn is_date(&self, input: &str) -> bool {
+        self.parse_matched(input, &Utc::now()).is_some()
+    }
+
+    // Run the parsers in order and return the result of the first format that matches the input,
+    // or `None` when no format does. The clock is read once by the caller, so every format that
+    // falls back to the current date or time sees the same instant.
+    fn parse_matched(&self, input: &str, now: &DateTime<Utc>) -> Option<Result<DateTime<Utc>>> {
+        let normalized = normalize(input);
+        self.candidates(&normalized)
+            .find_map(|kind| self.parse_format(kind, &normalized, now))
+    }
+
+    // The formats to try for a normalized input, in order, leaving out the families whose
+    // prefix it does not match.
+    fn candidates(&self, normalized: &str) -> impl Iterator<Item = FormatKind> + '_ {
+        let families = FAMILIES.matches(normalized);
+        self.format_order
+            .iter()
+            .copied()
+            .filter(move |kind| match kind.family() {
+                Some(index) => families.matched(index),
+                None => true,
+            })
+    }
+
+    /// Same as [`Parse::parse`], but also returns [`ParseStats`] on how many formats were tried
+    /// and which one matched. The stats are only kept by this method, so [`Parse::parse`] does not
+    /// pay for them.
+    ///
+    /// ```
+    /// use chrono::prelude::*;
+    /// use dateparser::datetime::{FormatKind, Parse};
+    ///
+    /// let parse = Parse::new(&Utc, None);
+    /// let (parsed, stats) = parse.parse_instrumented("2021-05-01T01:17:02Z");
+    /// assert_eq!(parsed.unwrap(), Utc.ymd(2021, 5, 1).and_hms(1, 17, 2));
+    /// assert_eq!(stats.matched, Some(FormatKind::Rfc3339));
+    /// ```
+    pub fn parse_instrumented(&self, input: &str) -> (Result<DateTime<Utc>>, ParseStats) {
+        let mut stats = ParseStats::default();
+        if input.trim().is_empty() {
+            return (Err(ParseError::EmptyInput.into()), stats);
         }
-        if !RE.is_match(input) {
-            return None;
+        let normalized = normalize(input);
+        let now = Utc::now();
+        for kind in self.candidates(&normalized) {
+            stats.attempts += 1;
+            if let Some(parsed) = self.parse_format(kind, &normalized, &now) {
+                stats.matched = Some(kind);
+                return (parsed, stats);
+            }
         }
-        self.rfc3339(input)
-            .or_else(|| self.postgres_timestamp(input))
-            .or_else(|| self.ymd_hms(input))
-            .or_else(|| self.ymd_hms_z(input))
-            .or_else(|| self.ymd(input))
-            .or_else(|| self.ymd_z(input))
+        (Err(anyhow!("{} did not match any formats.", input)), stats)
+    }
+
+    /// Parse each line read from the reader, such as a file or stdin, and yield the line along
+    /// with the result of [`Parse::parse`] for the line with its surrounding whitespace trimmed. The
+    /// clock is read once for the whole stream, so that every line without a date or a time falls
+    /// back to the same instant. A line that cannot be read is yielded as an empty line with an
+    /// error that has its line number, and ends the stream. It needs the `std` feature, which is
+    /// on by default.
+    ///
+    /// ```
+    /// use chrono::prelude::*;
+    /// use dateparser::datetime::Parse;
+    ///
+    /// let parse = Parse::new(&Utc, None);
+    /// let input = "2021-05-01 10:00:00 UTC\nnot a date\n";
+    /// let results: Vec<_> = parse.parse_lines(input.as_bytes()).collect();
+    /// assert_eq!(results[0].0, "2021-05-01 10:00:00 UTC");
+    /// assert_eq!(results[0].1.as_ref().unwrap(), &Utc.ymd(2021, 5, 1).and_hms(10, 0, 0));
+    /// assert!(results[1].1.is_err());
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn parse_lines<'a, R: std::io::BufRead + 'a>(
+        &'a self,
+        reader: R,
+    ) -> impl Iterator<Item = (String, Result<DateTime<Utc>>)> + 'a {
+        let now = Utc::now();
+        // a reader that failed once may keep failing, so the stream stops at the first error
+        reader
+            .lines()
+            .enumerate()
+            .scan(false, move |failed, (index, line)| {
+                if *failed {
+                    return None;
+                }
+                Some(match line {
+                    Ok(line) => {
+                        let parsed = self.parse_at(line.trim(), &now);
+                        (line, parsed)
+                    }
+                    Err(err) => {
+                        *failed = true;
+                        let err = anyhow!("line {} could not be read: {}", index + 1, err);
+                        (String::new(), Err(err))
+                    }
+                })
+            })
     }
 
-    fn hms_family(&self, input: &str) -> Option<Result<DateTime<Utc>>> {
-        lazy_static! {
-            static ref RE: Regex = Regex::new(r"^[0-9]{1,2}:[0-9]{2}").unwrap();
+    /// Similar to [`Parse::parse`], but the all-zeros date used by MySQL for "no date", such as
+    /// `0000-00-00` and `0000-00-00 00:00:00`, is returned as `Ok(None)` instead of
+    /// [`ParseError::ZeroDate`].
+    pub fn parse_opt(&self, input: &str) -> Result<Option<DateTime<Utc>>> {
+        match self.parse(input) {
+            Ok(parsed) => Ok(Some(parsed)),
+            Err(err) if err.downcast_ref::<ParseError>() == Some(&ParseError::ZeroDate) => Ok(None),
+            Err(err) => Err(err),
         }
-        if !RE.is_match(input) {
-            return None;
+    }
+
+    /// Unlike [`Parse::parse`], which stops at the first accepted format that matches, this method
+    /// tries every accepted format and returns each distinct datetime the input can be read as,
+    /// along with the name of the format that produced it. It helps to show all plausible
+    /// readings of an ambiguous input. Formats that do not match or fail to parse are skipped.
+    pub fn parse_all(&self, input: &str) -> Vec<(DateTime<Utc>, &'static str)> {
+        let normalized = normalize(input);
+        let now = Utc::now();
+        let mut parsed: Vec<(DateTime<Utc>, &'static str)> = Vec::new();
+        for &kind in self.format_order.iter() {
+            if let Some(Ok(datetime)) = self.parse_format(kind, &normalized, &now) {
+                if !parsed.iter().any(|(seen, _)| *seen == datetime) {
+                    parsed.push((datetime, kind.name()));
+                }
+            }
         }
-        self.hms(input).or_else(|| self.hms_z(input))
+        parsed
     }
 
-    fn month_mdy_family(&self, input: &str) -> Option<Result<DateTime<Utc>>> {
-        lazy_static! {
-            static ref RE: Regex = Regex::new(r"^[a-zA-Z]{3,9}\.?\s+[0-9]{1,2}").unwrap();
+    /// Parse a range of two datetimes in one string, such as `2021-01-01 to 2021-01-31`. The
+    /// start and end are separated by ` to `, a spaced dash ` - `, `..` or `—`, and each of them is
+    /// parsed by [`Parse::parse`]. A dash without spaces around it is never taken as a separator,
+    /// so it does not split `2021-01-01`. It returns an error when the start is after the end.
+    pub fn parse_range(&self, input: &str) -> Result<(DateTime<Utc>, DateTime<Utc>)> {
+        for separator in [" to ", " - ", "..", "—"] {
+            if let Some((start, end)) = input.split_once(separator) {
+                let start = self.parse(start.trim())?;
+                let end = self.parse(end.trim())?;
+                if start > end {
+                    return Err(anyhow!("{} starts after it ends.", input));
+                }
+                return Ok((start, end));
+            }
         }
-        if !RE.is_match(input) {
-            return None;
+        Err(anyhow!("{} is not a range of datetimes.", input))
+    }
+
+    /// Find the first datetime inside of a longer text, such as a log line or a message, and
+    /// return it along with the byte range it takes up in the text. A datetime can start wherever
+    /// a weekday, `next`, `last` or `this`, a business day shorthand such as `EOD`, or one of the
+    /// accepted formats with a known prefix, e.g. `yyyy-mm-dd`, `hh:mm` or `Mon dd`, starts at a
+    /// word boundary, and the longest run of words from there that [`Parse::parse`] accepts is
+    /// taken, leaving out trailing punctuation such as a comma, a full stop or a closing bracket.
+    ///
+    /// ```
+    /// use chrono::prelude::*;
+    /// use dateparser::datetime::Parse;
+    ///
+    /// let text = "Backup completed at 2021-05-01 10:00:00 UTC successfully";
+    /// let (parsed, range) = Parse::new(&Utc, None).parse_first_in_text(text).unwrap();
+    /// assert_eq!(parsed, Utc.ymd(2021, 5, 1).and_hms(10, 0, 0));
+    /// assert_eq!(&text[range], "2021-05-01 10:00:00 UTC");
+    /// ```
+    pub fn parse_first_in_text(&self, input: &str) -> Result<(DateTime<Utc>, Range<usize>)> {
+        let mut from = 0;
+        while let Some(found) = FAMILIES_IN_TEXT.find_at(input, from) {
+            let start = found.start();
+            let rest = &input[start..];
+            if let Some((parsed, len)) = self.parse_longest_prefix(rest) {
+                return Ok((parsed, start..start + len));
+            }
+            from = start + rest.chars().next().map_or(1, char::len_utf8);
         }
-        self.month_md_hms(input)
-            .or_else(|| self.month_mdy_hms(input))
-            .or_else(|| self.month_mdy_hms_z(input))
-            .or_else(|| self.month_mdy(input))
+        Err(anyhow!("{} does not contain any datetime.", input))
+    }
+
+    /// Parse a datetime from the start of the input, and return it along with the rest of the
+    /// input after it, such as the message of a log line. The longest run of words from the start
+    /// that [`Parse::parse`] accepts is taken, leaving out trailing punctuation such as a comma, the
+    /// same as [`Parse::parse_first_in_text`] does.
+    ///
+    /// ```
+    /// use chrono::prelude::*;
+    /// use dateparser::datetime::Parse;
+    ///
+    /// let parse = Parse::new(&Utc, None);
+    /// let (parsed, rest) = parse.parse_prefix("2021-05-01 10:00:00 UTC backup completed").unwrap();
+    /// assert_eq!(parsed, Utc.ymd(2021, 5, 1).and_hms(10, 0, 0));
+    /// assert_eq!(rest, " backup completed");
+    /// ```
+    pub fn parse_prefix<'a>(&self, input: &'a str) -> Result<(DateTime<Utc>, &'a str)> {
+        self.parse_longest_prefix(input)
+            .map(|(parsed, len)| (parsed, &input[len..]))
+            .ok_or_else(|| anyhow!("{} does not start with a datetime.", input))
     }
 
-    fn month_dmy_family(&self, input: &str) -> Option<Result<DateTime<Utc>>> {
-        lazy_static! {
-            static ref RE: Regex = Regex::new(r"^[0-9]{1,2}\s+[a-zA-Z]{3,9}").unwrap();
+    // Find the longest run of words from the start of the text that parses, and return the parsed
+    // datetime along with the length of the run in bytes.
+    fn parse_longest_prefix(&self, text: &str) -> Option<(DateTime<Utc>, usize)> {
+        static CALENDAR_WORD: Lazy<Regex> = Lazy::new(|| {
+            Regex::new(
+                r"(?i)^((jan|feb|mar|apr|may|jun|jul|aug|sep|oct|nov|dec|mon|tue|wed|thu|fri|sat|sun)[a-z]*|am|pm)$",
+            )
+            .unwrap()
+        });
+        // the accepted formats with the most words, e.g. `Wed Jun  2 06:31:39 PDT 2021`, have
+        // fewer words than this
+        const MAX_WORDS: usize = 8;
+        // punctuation and closing brackets or quotes that a datetime in a text may be followed by
+        const TRAILING: [char; 10] = [',', '.', ';', ':', '!', '?', ')', ']', '"', '\''];
+
+        // the end of each word from the start, so that a datetime never ends mid-word
+        let mut ends: Vec<usize> = text
+            .char_indices()
+            .filter(|&(i, c)| c.is_whitespace() && !text[..i].ends_with(char::is_whitespace))
+            .map(|(i, _)| i)
+            .take(MAX_WORDS - 1)
+            .collect();
+        if ends.len() < MAX_WORDS - 1 {
+            ends.push(text.len());
         }
-        if !RE.is_match(input) {
-            return None;
+        for &end in ends.iter().rev() {
+            let candidate = text[..end].trim_end_matches(TRAILING);
+            // any word after a datetime, e.g. `first` in `2021-05-01 first`, could be read
+            // as an unknown time zone, so a datetime only ends with a word that is a known
+            // time zone, is part of a date or time, or is one an accepted format ends with
+            let last_word = candidate.rsplit(char::is_whitespace).next().unwrap_or("");
+            if candidate.len() > last_word.len()
+                && last_word.chars().all(|c| c.is_ascii_alphabetic())
+                && !timezone::is_known_name(last_word)
+                && !CALENDAR_WORD.is_match(last_word)
+                && !self.format_order.iter().any(|&kind| {
+                    self.is_allowed(kind)
+                        && kind
+                            .last_words()
+                            .iter()
+                            .any(|word| last_word.eq_ignore_ascii_case(word))
+                })
+            {
+                continue;
+            }
+            if let Ok(parsed) = self.parse(candidate) {
+                return Some((parsed, candidate.len()));
+            }
         }
-        self.month_dmy_hms(input).or_else(|| self.month_dmy(input))
+        None
+    }
+
+    /// Parse an elapsed time written as a clock time, `hh:mm:ss`, into a [`chrono::Duration`].
+    /// Unlike wall-clock times, hours are not limited to 23, e.g. `25:30:00` is 25 hours and 30
+    /// minutes. The result is a duration, not a datetime, so it is neither tied to a date nor to
+    /// the parsing timezone.
+    pub fn parse_elapsed(&self, input: &str) -> Result<chrono::Duration> {
+        static RE: Lazy<Regex> = Lazy::new(|| {
+            Regex::new(r"^(?P<hours>[0-9]+):(?P<minutes>[0-5][0-9]):(?P<seconds>[0-5][0-9])$")
+                .unwrap()
+        });
+        let caps = RE
+            .captures(input)
+            .ok_or_else(|| anyhow!("{} did not match the elapsed time format.", input))?;
+
+        let hours = caps["hours"]
+            .parse::<i64>()
+            .map_err(|_| anyhow!("{} has too many hours.", input))?;
+        let minutes = caps["minutes"].parse::<i64>()?;
+        let seconds = caps["seconds"].parse::<i64>()?;
+        hours
+            .checked_mul(3600)
+            .and_then(|h| h.checked_add(minutes * 60 + seconds))
+            .filter(|total| *total <= chrono::Duration::max_value().num_seconds())
+            .map(chrono::Duration::seconds)
+            .ok_or_else(|| anyhow!("{} has too many hours.", input))
+    }
+
+    // Run the parser of a single format.
+    fn parse_format(
+        &self,
+        kind: FormatKind,
+        input: &str,
+        now: &DateTime<Utc>,
+    ) -> Option<Result<DateTime<Utc>>> {
+        static T_SEPARATOR: Lazy<Regex> =
+            Lazy::new(|| Regex::new(r"^([0-9]{4}-[0-9]{2}-[0-9]{2})T").unwrap());
+        // only rfc3339 takes a `T` between date and time, the rest of the yyyy-mm-dd formats
+        // expect a space, so `2021-05-14T18:51:00 GMT` is read as `2021-05-14 18:51:00 GMT`
+        let spaced = || T_SEPARATOR.replace(input, "$1 ");
+
+        let parsed = match kind {
+            FormatKind::Custom => self.custom(input, now),
+            FormatKind::UnixTimestamp => self.unix_timestamp(input),
+            FormatKind::UnixTimestampUnit => self.unix_timestamp_unit(input),
+            FormatKind::Rfc2822 => self.rfc2822(input),
+            FormatKind::CookieDate => self.cookie_date(input),
+            FormatKind::Rfc850 => self.rfc850(input),
+            FormatKind::AnsiC => self.ansi_c(input),
+            FormatKind::UnixDate => self.unix_date(input),
+            FormatKind::JsDateString => self.js_date_string(input),
+            FormatKind::BusinessDay => self.business_day(input, now),
+            FormatKind::ZeroDate => self.zero_date(input),
+            FormatKind::Rfc3339 => self.rfc3339(input),
+            FormatKind::PostgresTimestamp => self.postgres_timestamp(&spaced()),
+            FormatKind::GoTimeString => self.go_time_string(input),
+            FormatKind::YmdHms => self.ymd_hms(&spaced()),
+            FormatKind::YmdHmsZ => self.ymd_hms_z(&spaced()),
+            FormatKind::Ymd => self.ymd(&spaced(), now),
+            FormatKind::YmdZ => self.ymd_z(&spaced(), now),
+            FormatKind::NegativeYmd => self.negative_ymd(input, now),
+            FormatKind::YyMmDd => self.yy_mm_dd(input, now),
+            FormatKind::BasicIsoDatetime => self.basic_iso_datetime(input),
+            FormatKind::BasicIsoDate => self.basic_iso_date(input, now),
+            FormatKind::Hms => self.hms(input, now),
+            FormatKind::HmsZ => self.hms_z(input, now),
+            FormatKind::HourMeridiem => self.hour_meridiem(input, now),
+            FormatKind::CompactHm => self.compact_hm(input, now),
+            FormatKind::MonthYmd => self.month_ymd(input, now),
+            FormatKind::Quarter => self.quarter(input, now),
+            FormatKind::YearMonth => self.year_month(input, now),
+            FormatKind::EraYear => self.era_year(input, now),
+            FormatKind::WeekOfYear => self.week_of_year(input, now),
+            FormatKind::RelativeWeekday => self.relative_weekday(input, now),
+            FormatKind::IsoDuration => self.iso_duration(input, now),
+            FormatKind::MonthMdHms => self.month_md_hms(input, now),
+            FormatKind::MonthMdyHms => self.month_mdy_hms(input),
+            FormatKind::MonthMdyHmsZ => self.month_mdy_hms_z(input),
+            FormatKind::MonthMdy => self.month_mdy(input, now),
+            FormatKind::MonthDmyHms => self.month_dmy_hms(input),
+            FormatKind::MonthDmy => self.month_dmy(input, now),
+            FormatKind::SlashMdyHms => self.slash_mdy_hms(input),
+            FormatKind::SlashMdy => self.slash_mdy(input, now),
+            FormatKind::SlashYmdHms => self.slash_ymd_hms(input),
+            FormatKind::SlashYmd => self.slash_ymd(input, now),
+            FormatKind::DotMdyOrYmd => self.dot_mdy_or_ymd(input, now),
+            FormatKind::MysqlLogTimestamp => self.mysql_log_timestamp(input),
+            FormatKind::ChineseYmdHms => self.chinese_ymd_hms(input),
+            FormatKind::ChineseYmdMeridiemHms => self.chinese_ymd_meridiem_hms(input),
+            FormatKind::ChineseYmd => self.chinese_ymd(input, now),
+            FormatKind::KoreanYmdHms => self.korean_ymd_hms(input),
+            FormatKind::KoreanYmd => self.korean_ymd(input, now),
+        }?;
+        Some(parsed.and_then(|parsed| self.check_year_bounds(parsed)))
     }
 
-    fn slash_mdy_family(&self, input: &str) -> Option<Result<DateTime<Utc>>> {
-        lazy_static! {
-            static ref RE: Regex = Regex::new(r"^[0-9]{1,2}/[0-9]{1,2}").unwrap();
+    // Reject a parsed datetime whose year in the parsing timezone is out of the `year_bounds`.
+    fn check_year_bounds(&self, parsed: DateTime<Utc>) -> Result<DateTime<Utc>> {
+        match &self.year_bounds {
+            Some(bounds) => {
+                let year = parsed.with_timezone(&*self.tz).year();
+                if bounds.contains(&year) {
+                    Ok(parsed)
+                } else {
+                    Err(ParseError::YearOutOfBounds(year).into())
+                }
+            }
+            None => Ok(parsed),
         }
-        if !RE.is_match(input) {
-            return None;
+    }
+
+    // Time of a date-only input: the default time when given, otherwise the one picked by the
+    // `DateOnlyPolicy`, with the current time read from `now` in the timezone of the date.
+    fn date_only_time<Tz: TimeZone>(&self, now: &DateTime<Tz>) -> NaiveTime {
+        match (self.default_time, self.date_only_policy) {
+            (Some(time), _) => time,
+            (None, DateOnlyPolicy::CurrentTime) => now.time(),
+            (None, DateOnlyPolicy::StartOfDay) => NaiveTime::from_hms(0, 0, 0),
         }
-        self.slash_mdy_hms(input).or_else(|| self.slash_mdy(input))
     }
 
-    fn slash_ymd_family(&self, input: &str) -> Option<Result<DateTime<Utc>>> {
-        lazy_static! {
-            static ref RE: Regex = Regex::new(r"^[0-9]{4}/[0-9]{1,2}").unwrap();
+    // Runs the 12-hour am/pm format attempts only when they are allowed, otherwise keeps the error
+    // from the 24-hour attempts before them.
+    fn twelve_hour<T>(
+        &self,
+        err: chrono::ParseError,
+        attempts: impl FnOnce() -> chrono::ParseResult<T>,
+    ) -> chrono::ParseResult<T> {
+        if self.allow_12_hour {
+            attempts()
+        } else {
+            Err(err)
         }
-        if !RE.is_match(input) {
-            return None;
+    }
+
+    // Format of a slash separated date with the year first, in the `DateOrder`.
+    fn slash_year_first(&self) -> &'static str {
+        match self.date_order {
+            DateOrder::Ymd => "%Y/%m/%d",
+            DateOrder::Ydm => "%Y/%d/%m",
         }
-        self.slash_ymd_hms(input).or_else(|| self.slash_ymd(input))
     }
 
-    fn chinese_ymd_family(&self, input: &str) -> Option<Result<DateTime<Utc>>> {
-        lazy_static! {
-            static ref RE: Regex = Regex::new(r"^[0-9]{4}年[0-9]{2}月").unwrap();
+    // Convert a local datetime in the parsing timezone to UTC. A local datetime skipped by the
+    // parsing timezone is reported as `ParseError::NonexistentLocalTime` rather than falling
+    // through to the next parser, and a local datetime that occurs twice is resolved by the
+    // `DstPolicy`.
+    fn local_to_utc(&self, datetime: &NaiveDateTime) -> Option<Result<DateTime<Utc>>> {
+        match self.tz.from_local_datetime(datetime) {
+            LocalResult::Single(at_tz) => Some(Ok(at_tz.with_timezone(&Utc))),
+            LocalResult::Ambiguous(earliest, latest) => match self.dst_policy {
+                DstPolicy::Earliest => Some(Ok(earliest.with_timezone(&Utc))),
+                DstPolicy::Latest => Some(Ok(latest.with_timezone(&Utc))),
+                DstPolicy::Error => Some(Err(ParseError::AmbiguousLocalTime(*datetime).into())),
+            },
+            LocalResult::None => Some(Err(ParseError::NonexistentLocalTime(*datetime).into())),
         }
-        if !RE.is_match(input) {
-            return None;
+    }
+
+    // custom strftime formats given by `custom_formats` and `custom_date_formats`
+    // - 08|05|2021 10:30 (with `%d|%m|%Y %H:%M`)
+    // - 08|05|2021 10:30 +0200 (with `%d|%m|%Y %H:%M %z`)
+    // - 08|05|2021 (with `%d|%m|%Y`)
+    fn custom(&self, input: &str, now: &DateTime<Utc>) -> Option<Result<DateTime<Utc>>> {
+        for format in self.custom_formats.iter() {
+            if let Ok(parsed) = DateTime::parse_from_str(input, format) {
+                return Some(Ok(parsed.with_timezone(&Utc)));
+            }
+            if let Ok(parsed) = NaiveDateTime::parse_from_str(input, format) {
+                return self.local_to_utc(&parsed);
+            }
+        }
+        for format in self.custom_date_formats.iter() {
+            if let Ok(parsed) = NaiveDate::parse_from_str(input, format) {
+                let time = self.date_only_time(&now.with_timezone(&*self.tz));
+                return self.local_to_utc(&parsed.and_time(time));
+            }
         }
-        self.chinese_ymd_hms(input)
-            .or_else(|| self.chinese_ymd(input))
+        None
     }
 
     // unix timestamp
     // - 1511648546
     // - 1620021848429
+    // - 1620024872717915
     // - 1620024872717915000
+    // - 16200218484 (11 digits, read as milliseconds)
+    // - @@1511648546@@ (with epoch sentinels)
+    // - @1511648546
+    // - epoch
+    // - 1_511_648_546
     fn unix_timestamp(&self, input: &str) -> Option<Result<DateTime<Utc>>> {
-        lazy_static! {
-            static ref RE: Regex = Regex::new(r"^[0-9]{10,19}$").unwrap();
+        let input = match &self.epoch_sentinels {
+            Some((prefix, suffix)) => input
+                .strip_prefix(prefix.as_str())
+                .and_then(|stripped| stripped.strip_suffix(suffix.as_str()))
+                .unwrap_or(input),
+            None => input,
+        };
+
+        if input.eq_ignore_ascii_case("epoch") {
+            return Some(Ok(Utc.timestamp(0, 0)));
         }
-        if !RE.is_match(input) {
+        // an `@` prefix, as GNU date takes it, is always followed by seconds of any length
+        if let Some(seconds) = input.strip_prefix('@') {
+            let digits = seconds.strip_prefix('-').unwrap_or(seconds);
+            if !(1..=19).contains(&digits.len()) || !digits.bytes().all(|b| b.is_ascii_digit()) {
+                return None;
+            }
+            return seconds
+                .parse::<i64>()
+                .ok()
+                .and_then(|timestamp| Utc.timestamp_opt(timestamp, 0).single())
+                .map(Ok);
+        }
+
+        // underscores between digits, e.g. `1_620_021_848`, are only there for readability, so
+        // the number of digits is counted without them
+        let without_underscores;
+        let input = if input.contains('_') {
+            if input.starts_with('_') || input.ends_with('_') || input.contains("__") {
+                return None;
+            }
+            without_underscores = input.replace('_', "");
+            without_underscores.as_str()
+        } else {
+            input
+        };
+
+        // it is the first format tried for every input, so plain byte checks stand in for the
+        // regex `^[0-9]{10,19}$`, which is much slower to reject a non-numeric input
+        if !(10..=19).contains(&input.len()) || !input.bytes().all(|b| b.is_ascii_digit()) {
             return None;
         }
 
-        input
-            .parse::<i64>()
-            .ok()
-            .and_then(|timestamp| {
-                match input.len() {
-                    10 => Some(Utc.timestamp(timestamp, 0)),
-                    13 => Some(Utc.timestamp_millis(timestamp)),
-                    19 => Some(Utc.timestamp_nanos(timestamp)),
-                    _ => None,
-                }
-                .map(|datetime| datetime.with_timezone(&Utc))
-            })
+        let timestamp = input.parse::<i64>().ok()?;
+        // the unit is told by the number of digits a timestamp in it has these days: 10 for
+        // seconds, 13 for milliseconds, 16 for microseconds and 19 for nanoseconds
+        let in_unit = |digits: usize| match digits {
+            10 => Utc.timestamp_opt(timestamp, 0).single(),
+            13 => Utc.timestamp_millis_opt(timestamp).single(),
+            16 => Utc.timestamp_micros(timestamp).single(),
+            _ => Some(Utc.timestamp_nanos(timestamp)),
+        };
+        if UNIT_DIGITS.contains(&input.len()) {
+            return in_unit(input.len()).map(Ok);
+        }
+
+        // a compact datetime, e.g. `20210501101112` or `202105011011`, is not a timestamp
+        if NaiveDateTime::parse_from_str(input, "%Y%m%d%H%M%S").is_ok()
+            || NaiveDateTime::parse_from_str(input, "%Y%m%d%H%M").is_ok()
+        {
+            return None;
+        }
+
+        // any other length is read in the unit closest to it that gives a plausible year, e.g.
+        // 11 digits are too many for seconds until the year 2286, so they are milliseconds
+        let mut units = UNIT_DIGITS;
+        units.sort_by_key(|&digits| (digits.abs_diff(input.len()), digits));
+        units
+            .into_iter()
+            .filter_map(in_unit)
+            .find(|datetime| PLAUSIBLE_YEARS.contains(&datetime.year()))
             .map(Ok)
     }
 
+    // unix timestamp with a unit suffix, which is used instead of guessing the unit from the number
+    // of digits
+    // - 1620021848s
+    // - 1620021848429ms
+    // - 1620024872717915us
+    // - 1620024872717915000ns
+    fn unix_timestamp_unit(&self, input: &str) -> Option<Result<DateTime<Utc>>> {
+        static RE: Lazy<Regex> =
+            Lazy::new(|| Regex::new(r"^(?P<timestamp>[0-9]{1,19})(?P<unit>s|ms|us|ns)$").unwrap());
+        let caps = RE.captures(input)?;
+
+        let timestamp = caps["timestamp"].parse::<i64>().ok()?;
+        match &caps["unit"] {
+            "s" => Utc.timestamp_opt(timestamp, 0).single(),
+            "ms" => Utc.timestamp_millis_opt(timestamp).single(),
+            "us" => Utc.timestamp_micros(timestamp).single(),
+            _ => Some(Utc.timestamp_nanos(timestamp)),
+        }
+        .map(Ok)
+    }
+
+    // mysql zero date
+    // - 0000-00-00
+    // - 0000-00-00 00:00:00
+    fn zero_date(&self, input: &str) -> Option<Result<DateTime<Utc>>> {
+        static RE: Lazy<Regex> =
+            Lazy::new(|| Regex::new(r"^0000-00-00(\s+00:00(:00(\.0{1,9})?)?)?$").unwrap());
+        if !RE.is_match(input) {
+            return None;
+        }
+
+        Some(Err(ParseError::ZeroDate.into()))
+    }
+
     // rfc3339
     // - 2021-05-01T01:17:02.604456Z
     // - 2017-11-25T22:34:50Z
+    // - 2021-05-01t01:17:02z
     fn rfc3339(&self, input: &str) -> Option<Result<DateTime<Utc>>> {
+        static LOWERCASE: Lazy<Regex> = Lazy::new(|| {
+            Regex::new(
+                r"^(?P<date>[0-9]{4}-[0-9]{2}-[0-9]{2})[tT](?P<time>[0-9]{2}:[0-9]{2}:[0-9]{2}(\.[0-9]+)?)(?P<tz>[zZ]|[+-][0-9]{2}:[0-9]{2})$",
+            )
+            .unwrap()
+        });
         DateTime::parse_from_rfc3339(input)
             .ok()
+            .or_else(|| {
+                // some producers write the `T` and `Z` separators in lowercase
+                let caps = LOWERCASE.captures(input)?;
+                let upcased = format!(
+                    "{}T{}{}",
+                    &caps["date"],
+                    &caps["time"],
+                    caps["tz"].to_uppercase()
+                );
+                DateTime::parse_from_rfc3339(&upcased).ok()
+            })
             .map(|parsed| parsed.with_timezone(&Utc))
             .map(Ok)
     }
 
     // rfc2822
     // - Wed, 02 Jun 2021 06:31:39 GMT
+    // - Wed, 02 Jun 2021 06:31:39 -0000
+    //
+    // RFC 2822 reads `-0000` as "the local offset is unknown" and `+0000` as "the local offset is
+    // UTC", but both are the same instant. Only the instant is returned, so the two collapse into
+    // one on purpose and the sign of a zero offset is not kept.
     fn rfc2822(&self, input: &str) -> Option<Result<DateTime<Utc>>> {
         DateTime::parse_from_rfc2822(input)
             .ok()
@@ -164,6 +1461,126 @@ where
             .map(Ok)
     }
 
+    // cookie date, as in the HTTP Set-Cookie expires attribute
+    // - Wed, 09-Jun-2021 10:18:14 GMT
+    // - Wed, 09-Jun-21 10:18:14 GMT
+    fn cookie_date(&self, input: &str) -> Option<Result<DateTime<Utc>>> {
+        static RE: Lazy<Regex> = Lazy::new(|| {
+            Regex::new(
+                r"^[a-zA-Z]{3},\s+[0-9]{1,2}-[a-zA-Z]{3}-([0-9]{2}|[0-9]{4})\s+[0-9]{2}:[0-9]{2}:[0-9]{2}(?P<tz>\s+[+-:a-zA-Z0-9]{3,9})$",
+            )
+            .unwrap()
+        });
+        let caps = RE.captures(input)?;
+
+        match timezone::parse(caps["tz"].trim()) {
+            Ok(offset) => {
+                let parse_from_str = NaiveDateTime::parse_from_str;
+                parse_from_str(input, "%a, %d-%b-%y %H:%M:%S %Z")
+                    .or_else(|_| parse_from_str(input, "%a, %d-%b-%Y %H:%M:%S %Z"))
+                    .ok()
+                    .and_then(|parsed| offset.from_local_datetime(&parsed).single())
+                    .map(|datetime| datetime.with_timezone(&Utc))
+                    .map(Ok)
+            }
+            Err(err) => Some(Err(err)),
+        }
+    }
+
+    // rfc850
+    // - Monday, 02-Jan-06 15:04:05 GMT
+    // - Wednesday, 02-Jun-21 06:31:39 PDT
+    fn rfc850(&self, input: &str) -> Option<Result<DateTime<Utc>>> {
+        static RE: Lazy<Regex> = Lazy::new(|| {
+            Regex::new(
+                r"^[a-zA-Z]{6,9},\s+[0-9]{1,2}-[a-zA-Z]{3}-[0-9]{2}\s+[0-9]{2}:[0-9]{2}:[0-9]{2}(?P<tz>\s+[+-:a-zA-Z0-9]{3,9})$",
+            )
+            .unwrap()
+        });
+        let caps = RE.captures(input)?;
+
+        // the two-digit year follows chrono's `%y`, so 70-99 are 19xx and 00-69 are 20xx
+        match timezone::parse(caps["tz"].trim()) {
+            Ok(offset) => NaiveDateTime::parse_from_str(input, "%A, %d-%b-%y %H:%M:%S %Z")
+                .ok()
+                .and_then(|parsed| offset.from_local_datetime(&parsed).single())
+                .map(|datetime| datetime.with_timezone(&Utc))
+                .map(Ok),
+            Err(err) => Some(Err(err)),
+        }
+    }
+
+    // ansi c asctime
+    // - Wed Jun  2 06:31:39 2021
+    // - Wed Jun 12 06:31:39 2021
+    fn ansi_c(&self, input: &str) -> Option<Result<DateTime<Utc>>> {
+        static RE: Lazy<Regex> = Lazy::new(|| {
+            Regex::new(
+                r"^[a-zA-Z]{3}\s+[a-zA-Z]{3}\s+[0-9 ]{1,2}\s+[0-9]{2}:[0-9]{2}:[0-9]{2}\s+[0-9]{4}$",
+            )
+            .unwrap()
+        });
+        if !RE.is_match(input) {
+            return None;
+        }
+
+        NaiveDateTime::parse_from_str(input, "%a %b %e %H:%M:%S %Y")
+            .ok()
+            .and_then(|parsed| self.local_to_utc(&parsed))
+    }
+
+    // unix date command output
+    // - Wed Jun  2 06:31:39 UTC 2021
+    // - Wed Jun  2 06:31:39 PDT 2021
+    fn unix_date(&self, input: &str) -> Option<Result<DateTime<Utc>>> {
+        static RE: Lazy<Regex> = Lazy::new(|| {
+            Regex::new(
+                r"^[a-zA-Z]{3}\s+(?P<date>[a-zA-Z]{3}\s+[0-9]{1,2})\s+(?P<time>[0-9]{2}:[0-9]{2}:[0-9]{2})\s+(?P<tz>[+-:a-zA-Z0-9]{3,9})\s+(?P<year>[0-9]{4})$",
+            )
+            .unwrap()
+        });
+        let caps = RE.captures(input)?;
+
+        match timezone::parse(&caps["tz"]) {
+            Ok(offset) => {
+                // the weekday is redundant, so it is left out rather than checked against the date
+                let dt = format!("{} {} {}", &caps["date"], &caps["time"], &caps["year"]);
+                NaiveDateTime::parse_from_str(&dt, "%b %e %H:%M:%S %Y")
+                    .ok()
+                    .and_then(|parsed| offset.from_local_datetime(&parsed).single())
+                    .map(|datetime| datetime.with_timezone(&Utc))
+                    .map(Ok)
+            }
+            Err(err) => Some(Err(err)),
+        }
+    }
+
+    // javascript Date.toString() output, after its trailing zone name in parentheses is stripped
+    // - Wed Jun 02 2021 06:31:39 GMT-0700
+    // - Thu Jul 01 2021 09:00:00 GMT+0200
+    // - Jun 02 2021 06:31:39 GMT-0700 (the leading weekday is stripped by `normalize`)
+    fn js_date_string(&self, input: &str) -> Option<Result<DateTime<Utc>>> {
+        static RE: Lazy<Regex> = Lazy::new(|| {
+            Regex::new(
+                r"^([a-zA-Z]{3}\s+)?(?P<datetime>[a-zA-Z]{3}\s+[0-9]{2}\s+[0-9]{4}\s+[0-9]{2}:[0-9]{2}:[0-9]{2})\s+GMT(?P<tz>[+-][0-9]{4})$",
+            )
+            .unwrap()
+        });
+        let caps = RE.captures(input)?;
+
+        match timezone::parse(&caps["tz"]) {
+            Ok(offset) => {
+                // the weekday is redundant, so it is left out rather than checked against the date
+                NaiveDateTime::parse_from_str(&caps["datetime"], "%b %d %Y %H:%M:%S")
+                    .ok()
+                    .and_then(|parsed| offset.from_local_datetime(&parsed).single())
+                    .map(|datetime| datetime.with_timezone(&Utc))
+                    .map(Ok)
+            }
+            Err(err) => Some(Err(err)),
+        }
+    }
+
     // postgres timestamp yyyy-mm-dd hh:mm:ss z
     // - 2019-11-29 08:08-08
     // - 2019-11-29 08:08:05-08
@@ -171,17 +1588,18 @@ where
     // - 2021-05-02 23:31:39.12689-07
     // - 2019-11-29 08:15:47.624504-08
     // - 2017-07-19 03:21:51+00:00
+    // - 2017-07-19 03:21:51+05:30:15 (seconds in the offset are dropped)
     fn postgres_timestamp(&self, input: &str) -> Option<Result<DateTime<Utc>>> {
-        lazy_static! {
-            static ref RE: Regex = Regex::new(
-                r"^[0-9]{4}-[0-9]{2}-[0-9]{2}\s+[0-9]{2}:[0-9]{2}(:[0-9]{2})?(\.[0-9]{1,9})?[+-:0-9]{3,6}$",
+        static RE: Lazy<Regex> = Lazy::new(|| {
+            Regex::new(
+                r"^(?P<datetime>[0-9]{4}-[0-9]{2}-[0-9]{2}\s+[0-9]{2}:[0-9]{2}(:[0-9]{2})?(\.[0-9]{1,9})?[+-][0-9]{2}(:?[0-9]{2})?)(:[0-9]{2})?$",
             )
-            .unwrap();
-        }
-        if !RE.is_match(input) {
-            return None;
-        }
+            .unwrap()
+        });
+        let caps = RE.captures(input)?;
 
+        // seconds in the offset, e.g. `+05:30:15`, are dropped, the same as `timezone::parse` does
+        let input = caps.name("datetime")?.as_str();
         DateTime::parse_from_str(input, "%Y-%m-%d %H:%M:%S%#z")
             .or_else(|_| DateTime::parse_from_str(input, "%Y-%m-%d %H:%M:%S%.f%#z"))
             .or_else(|_| DateTime::parse_from_str(input, "%Y-%m-%d %H:%M%#z"))
@@ -190,6 +1608,26 @@ where
             .map(Ok)
     }
 
+    // go time.Time.String() output yyyy-mm-dd hh:mm:ss z zone
+    // - 2021-05-01 10:00:00.123456789 +0000 UTC
+    // - 2021-05-01 18:00:00 +0800 CST
+    // - 2021-05-01 10:00:00.123456789 +0000 UTC m=+0.000012345
+    fn go_time_string(&self, input: &str) -> Option<Result<DateTime<Utc>>> {
+        static RE: Lazy<Regex> = Lazy::new(|| {
+            Regex::new(
+                r"^(?P<datetime>[0-9]{4}-[0-9]{2}-[0-9]{2}\s+[0-9]{2}:[0-9]{2}:[0-9]{2}(\.[0-9]{1,9})?\s+[+-][0-9]{4})\s+[a-zA-Z]{3,5}(\s+m=[+-][0-9]+\.[0-9]+)?$",
+            )
+            .unwrap()
+        });
+        let caps = RE.captures(input)?;
+
+        // the zone name and the monotonic clock reading are redundant with the numeric offset
+        DateTime::parse_from_str(&caps["datetime"], "%Y-%m-%d %H:%M:%S%.f %z")
+            .ok()
+            .map(|parsed| parsed.with_timezone(&Utc))
+            .map(Ok)
+    }
+
     // yyyy-mm-dd hh:mm:ss
     // - 2014-04-26 05:24:37 PM
     // - 2021-04-30 21:14
@@ -199,25 +1637,29 @@ where
     // - 2014-04-26 17:24:37.3186369
     // - 2012-08-03 18:31:59.257000000
     fn ymd_hms(&self, input: &str) -> Option<Result<DateTime<Utc>>> {
-        lazy_static! {
-            static ref RE: Regex = Regex::new(
+        static RE: Lazy<Regex> = Lazy::new(|| {
+            Regex::new(
                 r"^[0-9]{4}-[0-9]{2}-[0-9]{2}\s+[0-9]{2}:[0-9]{2}(:[0-9]{2})?(\.[0-9]{1,9})?\s*(am|pm|AM|PM)?$",
             )
-            .unwrap();
-        }
+            .unwrap()
+        });
         if !RE.is_match(input) {
             return None;
         }
 
-        self.tz
-            .datetime_from_str(input, "%Y-%m-%d %H:%M:%S")
-            .or_else(|_| self.tz.datetime_from_str(input, "%Y-%m-%d %H:%M"))
-            .or_else(|_| self.tz.datetime_from_str(input, "%Y-%m-%d %H:%M:%S%.f"))
-            .or_else(|_| self.tz.datetime_from_str(input, "%Y-%m-%d %I:%M:%S %P"))
-            .or_else(|_| self.tz.datetime_from_str(input, "%Y-%m-%d %I:%M %P"))
+        let parse_from_str = NaiveDateTime::parse_from_str;
+        parse_from_str(input, "%Y-%m-%d %H:%M:%S")
+            .or_else(|_| parse_from_str(input, "%Y-%m-%d %H:%M"))
+            .or_else(|_| parse_from_str(input, "%Y-%m-%d %H:%M:%S%.f"))
+            .or_else(|err| {
+                self.twelve_hour(err, || {
+                    parse_from_str(input, "%Y-%m-%d %I:%M:%S %P")
+                        .or_else(|_| parse_from_str(input, "%Y-%m-%d %I:%M %P"))
+                })
+            })
             .ok()
-            .map(|parsed| parsed.with_timezone(&Utc))
-            .map(Ok)
+            .and_then(|parsed| self.local_to_utc(&parsed))
+            .or_else(|| invalid_ymd(input))
     }
 
     // yyyy-mm-dd hh:mm:ss z
@@ -229,27 +1671,43 @@ where
     // - 2014-04-26 13:13:44 +09:00
     // - 2012-08-03 18:31:59.257000000 +0000
     // - 2015-09-30 18:48:56.35272715 UTC
+    // - 2014-04-26 05:24:37 PM PST
+    // - 2012-08-03 18:31:59 +0000UTC
     fn ymd_hms_z(&self, input: &str) -> Option<Result<DateTime<Utc>>> {
-        lazy_static! {
-            static ref RE: Regex = Regex::new(
-                r"^[0-9]{4}-[0-9]{2}-[0-9]{2}\s+[0-9]{2}:[0-9]{2}(:[0-9]{2})?(\.[0-9]{1,9})?(?P<tz>\s*[+-:a-zA-Z0-9]{3,6})$",
-            ).unwrap();
-        }
+        static RE: Lazy<Regex> = Lazy::new(|| {
+            Regex::new(
+                r"^[0-9]{4}-[0-9]{2}-[0-9]{2}\s+[0-9]{2}:[0-9]{2}(:[0-9]{2})?(\.[0-9]{1,9})?(\s*(am|pm|AM|PM)\b)?(?P<tz>\s*([+-][0-9]{2}:?[0-9]{2}(?P<name>[a-zA-Z]{2,5})|[+-:a-zA-Z0-9]{3,9}))$",
+            )
+            .unwrap()
+        });
 
         if !RE.is_match(input) {
             return None;
         }
         if let Some(caps) = RE.captures(input) {
             if let Some(matched_tz) = caps.name("tz") {
+                // a name glued to a numeric offset, as in `+0000UTC`, only repeats the offset
+                let zone = matched_tz.as_str().trim();
+                let zone = match caps.name("name") {
+                    Some(name) => &zone[..zone.len() - name.as_str().len()],
+                    None => zone,
+                };
                 let parse_from_str = NaiveDateTime::parse_from_str;
-                return match timezone::parse(matched_tz.as_str().trim()) {
+                return match timezone::parse(zone) {
                     Ok(offset) => parse_from_str(input, "%Y-%m-%d %H:%M:%S %Z")
                         .or_else(|_| parse_from_str(input, "%Y-%m-%d %H:%M %Z"))
                         .or_else(|_| parse_from_str(input, "%Y-%m-%d %H:%M:%S%.f %Z"))
+                        .or_else(|err| {
+                            self.twelve_hour(err, || {
+                                parse_from_str(input, "%Y-%m-%d %I:%M:%S %P %Z")
+                                    .or_else(|_| parse_from_str(input, "%Y-%m-%d %I:%M %P %Z"))
+                            })
+                        })
                         .ok()
                         .and_then(|parsed| offset.from_local_datetime(&parsed).single())
                         .map(|datetime| datetime.with_timezone(&Utc))
-                        .map(Ok),
+                        .map(Ok)
+                        .or_else(|| invalid_ymd(input)),
                     Err(err) => Some(Err(err)),
                 };
             }
@@ -259,38 +1717,31 @@ where
 
     // yyyy-mm-dd
     // - 2021-02-21
-    fn ymd(&self, input: &str) -> Option<Result<DateTime<Utc>>> {
-        lazy_static! {
-            static ref RE: Regex = Regex::new(r"^[0-9]{4}-[0-9]{2}-[0-9]{2}$").unwrap();
-        }
+    fn ymd(&self, input: &str, now: &DateTime<Utc>) -> Option<Result<DateTime<Utc>>> {
+        static RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"^[0-9]{4}-[0-9]{2}-[0-9]{2}$").unwrap());
 
         if !RE.is_match(input) {
             return None;
         }
 
         // set time to use
-        let time = match self.default_time {
-            Some(v) => v,
-            None => Utc::now().with_timezone(self.tz).time(),
-        };
+        let time = self.date_only_time(&now.with_timezone(&*self.tz));
 
         NaiveDate::parse_from_str(input, "%Y-%m-%d")
             .ok()
             .map(|parsed| parsed.and_time(time))
-            .and_then(|datetime| self.tz.from_local_datetime(&datetime).single())
-            .map(|at_tz| at_tz.with_timezone(&Utc))
-            .map(Ok)
+            .and_then(|datetime| self.local_to_utc(&datetime))
+            .or_else(|| invalid_ymd(input))
     }
 
     // yyyy-mm-dd z
     // - 2021-02-21 PST
     // - 2021-02-21 UTC
     // - 2020-07-20+08:00 (yyyy-mm-dd-07:00)
-    fn ymd_z(&self, input: &str) -> Option<Result<DateTime<Utc>>> {
-        lazy_static! {
-            static ref RE: Regex =
-                Regex::new(r"^[0-9]{4}-[0-9]{2}-[0-9]{2}(?P<tz>\s*[+-:a-zA-Z0-9]{3,6})$").unwrap();
-        }
+    fn ymd_z(&self, input: &str, now: &DateTime<Utc>) -> Option<Result<DateTime<Utc>>> {
+        static RE: Lazy<Regex> = Lazy::new(|| {
+            Regex::new(r"^[0-9]{4}-[0-9]{2}-[0-9]{2}(?P<tz>\s*[+-:a-zA-Z0-9]{3,6})$").unwrap()
+        });
         if !RE.is_match(input) {
             return None;
         }
@@ -300,16 +1751,14 @@ where
                 return match timezone::parse(matched_tz.as_str().trim()) {
                     Ok(offset) => {
                         // set time to use
-                        let time = match self.default_time {
-                            Some(v) => v,
-                            None => Utc::now().with_timezone(&offset).time(),
-                        };
+                        let time = self.date_only_time(&now.with_timezone(&offset));
                         NaiveDate::parse_from_str(input, "%Y-%m-%d %Z")
                             .ok()
                             .map(|parsed| parsed.and_time(time))
                             .and_then(|datetime| offset.from_local_datetime(&datetime).single())
                             .map(|at_tz| at_tz.with_timezone(&Utc))
                             .map(Ok)
+                            .or_else(|| invalid_ymd(input))
                     }
                     Err(err) => Some(Err(err)),
                 };
@@ -318,55 +1767,133 @@ where
         None
     }
 
-    // hh:mm:ss
-    // - 01:06:06
-    // - 4:00pm
-    // - 6:00 AM
-    fn hms(&self, input: &str) -> Option<Result<DateTime<Utc>>> {
-        lazy_static! {
-            static ref RE: Regex =
-                Regex::new(r"^[0-9]{1,2}:[0-9]{2}(:[0-9]{2})?\s*(am|pm|AM|PM)?$").unwrap();
-        }
+    // yy-mm-dd, or yy-dd-mm with `DateOrder::Ydm`
+    // - 21-02-21
+    fn yy_mm_dd(&self, input: &str, now: &DateTime<Utc>) -> Option<Result<DateTime<Utc>>> {
+        static RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"^[0-9]{2}-[0-9]{2}-[0-9]{2}$").unwrap());
         if !RE.is_match(input) {
             return None;
         }
 
-        let now = Utc::now().with_timezone(self.tz);
-        NaiveTime::parse_from_str(input, "%H:%M:%S")
-            .or_else(|_| NaiveTime::parse_from_str(input, "%H:%M"))
-            .or_else(|_| NaiveTime::parse_from_str(input, "%I:%M:%S %P"))
-            .or_else(|_| NaiveTime::parse_from_str(input, "%I:%M %P"))
+        // set time to use
+        let time = self.date_only_time(&now.with_timezone(&*self.tz));
+
+        let format = match self.date_order {
+            DateOrder::Ymd => "%y-%m-%d",
+            DateOrder::Ydm => "%y-%d-%m",
+        };
+        NaiveDate::parse_from_str(input, format)
             .ok()
-            .and_then(|parsed| now.date().and_time(parsed))
-            .map(|datetime| datetime.with_timezone(&Utc))
-            .map(Ok)
+            .map(|parsed| parsed.and_time(time))
+            .and_then(|datetime| self.local_to_utc(&datetime))
     }
 
-    // hh:mm:ss z
-    // - 01:06:06 PST
-    // - 4:00pm PST
-    // - 6:00 AM PST
-    // - 6:00pm UTC
-    fn hms_z(&self, input: &str) -> Option<Result<DateTime<Utc>>> {
-        lazy_static! {
-            static ref RE: Regex = Regex::new(
-                r"^[0-9]{1,2}:[0-9]{2}(:[0-9]{2})?\s*(am|pm|AM|PM)?(?P<tz>\s+[+-:a-zA-Z0-9]{3,6})$",
-            )
-            .unwrap();
+    // basic iso 8601 yyyymmddThhmmss, with an optional basic offset
+    // - 20210501T101112
+    // - 20210501T101112Z
+    // - 20210501T101112+0800
+    fn basic_iso_datetime(&self, input: &str) -> Option<Result<DateTime<Utc>>> {
+        static RE: Lazy<Regex> = Lazy::new(|| {
+            Regex::new(r"^(?P<datetime>[0-9]{8}T[0-9]{6})(?P<tz>Z|[+-][0-9]{2}([0-9]{2})?)?$")
+                .unwrap()
+        });
+        let caps = RE.captures(input)?;
+
+        let parsed = NaiveDateTime::parse_from_str(&caps["datetime"], "%Y%m%dT%H%M%S").ok()?;
+        match caps.name("tz").map(|tz| tz.as_str()) {
+            None => self.local_to_utc(&parsed),
+            Some("Z") => Some(Ok(Utc.from_utc_datetime(&parsed))),
+            Some(tz) => match timezone::parse(tz) {
+                Ok(offset) => offset
+                    .from_local_datetime(&parsed)
+                    .single()
+                    .map(|datetime| Ok(datetime.with_timezone(&Utc))),
+                Err(err) => Some(Err(err)),
+            },
         }
-        if !RE.is_match(input) {
+    }
+
+    // basic iso 8601 yyyymmdd
+    // - 20210501
+    fn basic_iso_date(&self, input: &str, now: &DateTime<Utc>) -> Option<Result<DateTime<Utc>>> {
+        // eight digits are too few for a unix timestamp, which takes at least ten
+        if input.len() != 8 || !input.bytes().all(|b| b.is_ascii_digit()) {
             return None;
         }
 
-        if let Some(caps) = RE.captures(input) {
-            if let Some(matched_tz) = caps.name("tz") {
-                return match timezone::parse(matched_tz.as_str().trim()) {
-                    Ok(offset) => {
-                        let now = Utc::now().with_timezone(&offset);
-                        NaiveTime::parse_from_str(input, "%H:%M:%S %Z")
+        // set time to use
+        let time = self.date_only_time(&now.with_timezone(&*self.tz));
+
+        NaiveDate::parse_from_str(input, "%Y%m%d")
+            .ok()
+            .map(|parsed| parsed.and_time(time))
+            .and_then(|datetime| self.local_to_utc(&datetime))
+    }
+
+    // hh:mm:ss
+    // - 01:06:06
+    // - 4:00pm
+    // - 3:04PM
+    // - 6:00 AM
+    // `hh:mm` is always read as hours and minutes of a wall-clock time, never as minutes and
+    // seconds, e.g. `01:06` is 1:06 AM. See `parse_elapsed` for a duration.
+    fn hms(&self, input: &str, now: &DateTime<Utc>) -> Option<Result<DateTime<Utc>>> {
+        static RE: Lazy<Regex> = Lazy::new(|| {
+            Regex::new(
+                r"^(?P<hour>[0-9]{1,2}):(?P<minute>[0-9]{2})(:(?P<second>[0-9]{2}))?\s*(?i:am|pm)?$",
+            )
+            .unwrap()
+        });
+        let caps = RE.captures(input)?;
+        let field = |name| caps.name(name).map_or(Ok(0), |m| m.as_str().parse::<u32>());
+        if field("hour").ok()? > 23 || field("minute").ok()? > 59 || field("second").ok()? > 60 {
+            return Some(Err(ParseError::InvalidTime(input.to_string()).into()));
+        }
+
+        let now = now.with_timezone(&*self.tz);
+        NaiveTime::parse_from_str(input, "%H:%M:%S")
+            .or_else(|_| NaiveTime::parse_from_str(input, "%H:%M"))
+            .or_else(|err| {
+                self.twelve_hour(err, || {
+                    NaiveTime::parse_from_str(input, "%I:%M:%S %P")
+                        .or_else(|_| NaiveTime::parse_from_str(input, "%I:%M %P"))
+                })
+            })
+            .ok()
+            .map(|parsed| now.date_naive().and_time(parsed))
+            .and_then(|datetime| self.local_to_utc(&datetime))
+    }
+
+    // hh:mm:ss z
+    // - 01:06:06 PST
+    // - 4:00pm PST
+    // - 6:00 AM PST
+    // - 6:00pm UTC
+    fn hms_z(&self, input: &str, now: &DateTime<Utc>) -> Option<Result<DateTime<Utc>>> {
+        static RE: Lazy<Regex> = Lazy::new(|| {
+            Regex::new(
+                r"^[0-9]{1,2}:[0-9]{2}(:[0-9]{2})?\s*(am|pm|AM|PM)?(?P<tz>\s+[+-:a-zA-Z0-9]{3,9})$",
+            )
+            .unwrap()
+        });
+        if !RE.is_match(input) {
+            return None;
+        }
+
+        if let Some(caps) = RE.captures(input) {
+            if let Some(matched_tz) = caps.name("tz") {
+                return match timezone::parse(matched_tz.as_str().trim()) {
+                    Ok(offset) => {
+                        let now = now.with_timezone(&offset);
+                        NaiveTime::parse_from_str(input, "%H:%M:%S %Z")
                             .or_else(|_| NaiveTime::parse_from_str(input, "%H:%M %Z"))
-                            .or_else(|_| NaiveTime::parse_from_str(input, "%I:%M:%S %P %Z"))
-                            .or_else(|_| NaiveTime::parse_from_str(input, "%I:%M %P %Z"))
+                            .or_else(|err| {
+                                self.twelve_hour(err, || {
+                                    NaiveTime::parse_from_str(input, "%I:%M:%S %P %Z").or_else(
+                                        |_| NaiveTime::parse_from_str(input, "%I:%M %P %Z"),
+                                    )
+                                })
+                            })
                             .ok()
                             .map(|parsed| now.date().naive_local().and_time(parsed))
                             .and_then(|datetime| offset.from_local_datetime(&datetime).single())
@@ -380,53 +1907,394 @@ where
         None
     }
 
+    // hh am/pm
+    // - 11AM
+    // - 3pm
+    // - 7 AM
+    fn hour_meridiem(&self, input: &str, now: &DateTime<Utc>) -> Option<Result<DateTime<Utc>>> {
+        static RE: Lazy<Regex> =
+            Lazy::new(|| Regex::new(r"^(?P<hour>[0-9]{1,2})\s*(?P<meridiem>(?i:am|pm))$").unwrap());
+        if !self.allow_12_hour {
+            return None;
+        }
+        let caps = RE.captures(input)?;
+
+        let now = now.with_timezone(&*self.tz);
+        let with_minute = format!("{}:00 {}", &caps["hour"], &caps["meridiem"]);
+        NaiveTime::parse_from_str(&with_minute, "%I:%M %P")
+            .ok()
+            .map(|parsed| now.date_naive().and_time(parsed))
+            .and_then(|datetime| self.local_to_utc(&datetime))
+    }
+
+    // hhmm, only when compact time is turned on
+    // - 1430
+    // - 0930
+    fn compact_hm(&self, input: &str, now: &DateTime<Utc>) -> Option<Result<DateTime<Utc>>> {
+        static RE: Lazy<Regex> =
+            Lazy::new(|| Regex::new(r"^([01][0-9]|2[0-3])[0-5][0-9]$").unwrap());
+        if !self.compact_time || !RE.is_match(input) {
+            return None;
+        }
+
+        let now = now.with_timezone(&*self.tz);
+        NaiveTime::parse_from_str(input, "%H%M")
+            .ok()
+            .map(|parsed| now.date_naive().and_time(parsed))
+            .and_then(|datetime| self.local_to_utc(&datetime))
+    }
+
     // yyyy-mon-dd
     // - 2021-Feb-21
-    fn month_ymd(&self, input: &str) -> Option<Result<DateTime<Utc>>> {
-        lazy_static! {
-            static ref RE: Regex = Regex::new(r"^[0-9]{4}-[a-zA-Z]{3,9}-[0-9]{2}$").unwrap();
-        }
+    fn month_ymd(&self, input: &str, now: &DateTime<Utc>) -> Option<Result<DateTime<Utc>>> {
+        static RE: Lazy<Regex> =
+            Lazy::new(|| Regex::new(r"^[0-9]{4}-[a-zA-Z]{3,9}-[0-9]{2}$").unwrap());
         if !RE.is_match(input) {
             return None;
         }
 
         // set time to use
-        let time = match self.default_time {
-            Some(v) => v,
-            None => Utc::now().with_timezone(self.tz).time(),
-        };
+        let time = self.date_only_time(&now.with_timezone(&*self.tz));
 
         NaiveDate::parse_from_str(input, "%Y-%m-%d")
             .or_else(|_| NaiveDate::parse_from_str(input, "%Y-%b-%d"))
             .ok()
             .map(|parsed| parsed.and_time(time))
-            .and_then(|datetime| self.tz.from_local_datetime(&datetime).single())
-            .map(|at_tz| at_tz.with_timezone(&Utc))
+            .and_then(|datetime| self.local_to_utc(&datetime))
+    }
+
+    // quarter, as the first day of the quarter
+    // - Q2 2021
+    // - 2021-Q2
+    fn quarter(&self, input: &str, now: &DateTime<Utc>) -> Option<Result<DateTime<Utc>>> {
+        static RE: Lazy<Regex> = Lazy::new(|| {
+            Regex::new(
+                r"^([qQ](?P<quarter>[1-4])\s+(?P<year>[0-9]{4})|(?P<year_first>[0-9]{4})-[qQ](?P<quarter_last>[1-4]))$",
+            )
+            .unwrap()
+        });
+        let caps = RE.captures(input)?;
+
+        let year: i32 = caps
+            .name("year")
+            .or(caps.name("year_first"))?
+            .as_str()
+            .parse()
+            .ok()?;
+        let quarter: u32 = caps
+            .name("quarter")
+            .or(caps.name("quarter_last"))?
+            .as_str()
+            .parse()
+            .ok()?;
+
+        // set time to use
+        let time = self.date_only_time(&now.with_timezone(&*self.tz));
+
+        NaiveDate::from_ymd_opt(year, (quarter - 1) * 3 + 1, 1)
+            .map(|parsed| parsed.and_time(time))
+            .and_then(|datetime| self.local_to_utc(&datetime))
+    }
+
+    // negative iso 8601 year, when `negative_years` is on
+    // - -0044-03-15
+    // - -0044-03-15 12:30:00
+    fn negative_ymd(&self, input: &str, now: &DateTime<Utc>) -> Option<Result<DateTime<Utc>>> {
+        static RE: Lazy<Regex> = Lazy::new(|| {
+            Regex::new(
+                r"^(?P<date>-[0-9]{4,6}-[0-9]{2}-[0-9]{2})([Tt\s]+(?P<time>[0-9]{2}:[0-9]{2}(:[0-9]{2}(\.[0-9]{1,9})?)?))?$",
+            )
+            .unwrap()
+        });
+        if !self.negative_years {
+            return None;
+        }
+        let caps = RE.captures(input)?;
+
+        let date = match NaiveDate::parse_from_str(&caps["date"], "%Y-%m-%d") {
+            Ok(date) => date,
+            Err(_) => return Some(Err(ParseError::InvalidDate(caps["date"].to_string()).into())),
+        };
+        let time = match caps.name("time") {
+            Some(time) => NaiveTime::parse_from_str(time.as_str(), "%H:%M:%S%.f")
+                .or_else(|_| NaiveTime::parse_from_str(time.as_str(), "%H:%M"))
+                .ok()?,
+            None => self.date_only_time(&now.with_timezone(&*self.tz)),
+        };
+        self.local_to_utc(&date.and_time(time))
+    }
+
+    // year month, as the first day of the month
+    // - 2021-05
+    // - 2021/05
+    // - May 2021
+    // - 2021-May
+    fn year_month(&self, input: &str, now: &DateTime<Utc>) -> Option<Result<DateTime<Utc>>> {
+        static RE: Lazy<Regex> = Lazy::new(|| {
+            Regex::new(
+                r"^([0-9]{4}[-/][0-9]{1,2}|[a-zA-Z]{3,9}\s+[0-9]{4}|[0-9]{4}-[a-zA-Z]{3,9})$",
+            )
+            .unwrap()
+        });
+        if !RE.is_match(input) {
+            return None;
+        }
+
+        // set time to use
+        let time = self.date_only_time(&now.with_timezone(&*self.tz));
+
+        let with_day = format!("{} 1", input);
+        NaiveDate::parse_from_str(&with_day, "%Y-%m %d")
+            .or_else(|_| NaiveDate::parse_from_str(&with_day, "%Y/%m %d"))
+            .or_else(|_| NaiveDate::parse_from_str(&with_day, "%B %Y %d"))
+            .or_else(|_| NaiveDate::parse_from_str(&with_day, "%Y-%B %d"))
+            .ok()
+            .map(|parsed| parsed.and_time(time))
+            .and_then(|datetime| self.local_to_utc(&datetime))
+    }
+
+    // year before the common era, as the first day of the year, when `negative_years` is on
+    // - 44 BC
+    // - 44 BCE
+    fn era_year(&self, input: &str, now: &DateTime<Utc>) -> Option<Result<DateTime<Utc>>> {
+        static RE: Lazy<Regex> =
+            Lazy::new(|| Regex::new(r"^(?P<year>[0-9]{1,6})\s*(?i:bce?)$").unwrap());
+        if !self.negative_years {
+            return None;
+        }
+        let caps = RE.captures(input)?;
+
+        // there is no year 0 BC, and 1 BC is year 0
+        let year = caps["year"].parse::<i32>().ok().filter(|year| *year > 0)?;
+        let time = self.date_only_time(&now.with_timezone(&*self.tz));
+        NaiveDate::from_ymd_opt(1 - year, 1, 1)
+            .map(|date| date.and_time(time))
+            .and_then(|datetime| self.local_to_utc(&datetime))
+    }
+
+    // week of year, as the monday of the iso week
+    // - Week 23 of 2021
+    // - 2021 week 23
+    fn week_of_year(&self, input: &str, now: &DateTime<Utc>) -> Option<Result<DateTime<Utc>>> {
+        static RE: Lazy<Regex> = Lazy::new(|| {
+            Regex::new(
+                r"^(?i:week\s+(?P<week>[0-9]{1,2})\s+of\s+(?P<year>[0-9]{4})|(?P<year_first>[0-9]{4})\s+week\s+(?P<week_last>[0-9]{1,2}))$",
+            )
+            .unwrap()
+        });
+        let caps = RE.captures(input)?;
+
+        let year: i32 = caps
+            .name("year")
+            .or(caps.name("year_first"))?
+            .as_str()
+            .parse()
+            .ok()?;
+        let week: u32 = caps
+            .name("week")
+            .or(caps.name("week_last"))?
+            .as_str()
+            .parse()
+            .ok()?;
+
+        // set time to use
+        let time = self.date_only_time(&now.with_timezone(&*self.tz));
+
+        // week 53 only exists in long years, and `from_isoywd_opt` rejects it for the others
+        NaiveDate::from_isoywd_opt(year, week, Weekday::Mon)
+            .map(|parsed| parsed.and_time(time))
+            .and_then(|datetime| self.local_to_utc(&datetime))
+    }
+
+    // a weekday, a week or a weekend relative to the current date, where a week starts on
+    // `week_start` and a weekend on the Saturday of its week
+    // - next Friday
+    // - last mon
+    // - this Sunday
+    // - next week
+    // - this weekend
+    fn relative_weekday(&self, input: &str, now: &DateTime<Utc>) -> Option<Result<DateTime<Utc>>> {
+        static RE: Lazy<Regex> = Lazy::new(|| {
+            Regex::new(r"^(?i:(?P<which>next|last|this)\s+(?P<unit>week|[a-z]{3,9}))$").unwrap()
+        });
+        let caps = RE.captures(input)?;
+
+        let now = now.with_timezone(&*self.tz);
+        let today = now.date_naive();
+        let start_of_week = today
+            - chrono::Duration::days(i64::from(
+                (today.weekday().num_days_from_monday() + 7
+                    - self.week_start.num_days_from_monday())
+                    % 7,
+            ));
+        let which = caps["which"].to_lowercase();
+        let unit = caps["unit"].to_lowercase();
+        let days_from = |from: Weekday, to: Weekday| {
+            i64::from((to.num_days_from_monday() + 7 - from.num_days_from_monday()) % 7)
+        };
+        let date = if unit == "week" || unit == "weekend" {
+            let start = match unit.as_str() {
+                "weekend" => {
+                    start_of_week + chrono::Duration::days(days_from(self.week_start, Weekday::Sat))
+                }
+                _ => start_of_week,
+            };
+            match which.as_str() {
+                "next" => start + chrono::Duration::days(7),
+                "last" => start - chrono::Duration::days(7),
+                _ => start,
+            }
+        } else {
+            let weekday = unit.parse::<Weekday>().ok()?;
+            match which.as_str() {
+                // the next or last one is never today, but a week from it
+                "next" => {
+                    today
+                        + chrono::Duration::days(match days_from(today.weekday(), weekday) {
+                            0 => 7,
+                            days => days,
+                        })
+                }
+                "last" => {
+                    today
+                        - chrono::Duration::days(match days_from(weekday, today.weekday()) {
+                            0 => 7,
+                            days => days,
+                        })
+                }
+                _ => start_of_week + chrono::Duration::days(days_from(self.week_start, weekday)),
+            }
+        };
+
+        // set time to use
+        let time = self.date_only_time(&now);
+
+        self.local_to_utc(&date.and_time(time))
+    }
+
+    // iso 8601 duration from now, optionally signed, with weeks, days, hours, minutes and seconds
+    // - P1D
+    // - -PT2H
+    // - P1W
+    // - PT1H30M15.5S
+    fn iso_duration(&self, input: &str, now: &DateTime<Utc>) -> Option<Result<DateTime<Utc>>> {
+        static RE: Lazy<Regex> = Lazy::new(|| {
+            Regex::new(
+                r"^[+-]?P((?P<years>[0-9]+)Y)?((?P<months>[0-9]+)M)?((?P<weeks>[0-9]+)W)?((?P<days>[0-9]+)D)?(T((?P<hours>[0-9]+)H)?((?P<minutes>[0-9]+)M)?((?P<seconds>[0-9]+)(\.(?P<fraction>[0-9]{1,9}))?S)?)?$",
+            )
+            .unwrap()
+        });
+        let caps = RE.captures(input)?;
+        // `P` and `PT` alone are not a duration
+        if input.ends_with('P') || input.ends_with('T') {
+            return None;
+        }
+        if caps.name("years").is_some() || caps.name("months").is_some() {
+            return Some(Err(anyhow!(
+                "{} has years or months, which are not a fixed length.",
+                input
+            )));
+        }
+
+        let component = |name: &str, unit: i64| {
+            caps.name(name).map_or(Some(0), |m| {
+                m.as_str().parse::<i64>().ok()?.checked_mul(unit)
+            })
+        };
+        let seconds = [
+            component("weeks", 7 * 86400),
+            component("days", 86400),
+            component("hours", 3600),
+            component("minutes", 60),
+            component("seconds", 1),
+        ]
+        .into_iter()
+        .try_fold(0i64, |total, seconds| total.checked_add(seconds?))
+        .filter(|total| *total <= chrono::Duration::max_value().num_seconds());
+        let nanos = caps.name("fraction").map_or(0, |m| {
+            format!("{:0<9}", m.as_str()).parse::<i64>().unwrap_or(0)
+        });
+        let negative = input.starts_with('-');
+
+        seconds
+            .and_then(|seconds| {
+                chrono::Duration::seconds(seconds)
+                    .checked_add(&chrono::Duration::nanoseconds(nanos))
+            })
+            .map(|duration| if negative { -duration } else { duration })
+            .and_then(|duration| now.checked_add_signed(duration))
             .map(Ok)
+            .or_else(|| Some(Err(anyhow!("{} is out of range.", input))))
+    }
+
+    // business day shorthands, on the given date or today
+    // - EOD, end of day, is 23:59:59
+    // - BOD, beginning of day, is 00:00:00
+    // - COB, close of business, is 17:00:00 unless set by `cob_hour`
+    // - 2021-05-01 EOD
+    // - May 1, 2021 COB
+    // A date with a time or a zone, e.g. `2021-05-01 10:30 EOD`, is reported as an error rather
+    // than left to the next parser, which would read the shorthand as an unknown time zone.
+    fn business_day(&self, input: &str, now: &DateTime<Utc>) -> Option<Result<DateTime<Utc>>> {
+        static RE: Lazy<Regex> = Lazy::new(|| {
+            Regex::new(r"^((?P<date>.*\S)\s+)?(?i:(?P<shorthand>eod|bod|cob))$").unwrap()
+        });
+        let caps = RE.captures(input)?;
+
+        let date = match caps.name("date") {
+            // the date may be written in any accepted format with only a date
+            Some(date) => match BUSINESS_DAY_DATES
+                .iter()
+                .filter(|&&kind| self.is_allowed(kind))
+                .find_map(|&kind| self.parse_format(kind, date.as_str(), now))
+            {
+                Some(Ok(parsed)) => parsed.with_timezone(&*self.tz).date_naive(),
+                Some(Err(err)) => return Some(Err(err)),
+                None => {
+                    return Some(Err(anyhow!(
+                        "{} is not a date without a time or zone.",
+                        date.as_str()
+                    )))
+                }
+            },
+            None => now.with_timezone(&*self.tz).date_naive(),
+        };
+        let time = match caps["shorthand"].to_lowercase().as_str() {
+            "eod" => NaiveTime::from_hms_opt(23, 59, 59),
+            "bod" => NaiveTime::from_hms_opt(0, 0, 0),
+            _ => NaiveTime::from_hms_opt(self.cob_hour, 0, 0),
+        }?;
+
+        self.local_to_utc(&date.and_time(time))
     }
 
     // Mon dd hh:mm:ss
-    // - May 6 at 9:24 PM
+    // - May 6 9:24 PM
+    // - May  6 9:24 PM
+    // - May 06 02:45:27
     // - May 27 02:45:27
-    fn month_md_hms(&self, input: &str) -> Option<Result<DateTime<Utc>>> {
-        lazy_static! {
-            static ref RE: Regex = Regex::new(
-                r"^[a-zA-Z]{3}\s+[0-9]{1,2}\s*(at)?\s+[0-9]{1,2}:[0-9]{2}(:[0-9]{2})?\s*(am|pm|AM|PM)?$",
+    fn month_md_hms(&self, input: &str, now: &DateTime<Utc>) -> Option<Result<DateTime<Utc>>> {
+        static RE: Lazy<Regex> = Lazy::new(|| {
+            Regex::new(
+                r"^[a-zA-Z]{3}\s+[0-9]{1,2}\s+[0-9]{1,2}:[0-9]{2}(:[0-9]{2})?\s*(am|pm|AM|PM)?$",
             )
-            .unwrap();
-        }
+            .unwrap()
+        });
         if !RE.is_match(input) {
             return None;
         }
 
-        let now = Utc::now().with_timezone(self.tz);
+        let now = now.with_timezone(&*self.tz);
         let with_year = format!("{} {}", now.year(), input);
-        self.tz
-            .datetime_from_str(&with_year, "%Y %b %d at %I:%M %P")
-            .or_else(|_| self.tz.datetime_from_str(&with_year, "%Y %b %d %H:%M:%S"))
+        let parse_from_str = NaiveDateTime::parse_from_str;
+        // when parsing, `%d` takes a day with or without a leading zero, and a space in the format
+        // takes any run of whitespace, so a space padded day, as syslog writes it, needs no `%e`
+        parse_from_str(&with_year, "%Y %b %d %H:%M:%S")
+            .or_else(|err| {
+                self.twelve_hour(err, || parse_from_str(&with_year, "%Y %b %d %I:%M %P"))
+            })
             .ok()
-            .map(|parsed| parsed.with_timezone(&Utc))
-            .map(Ok)
+            .and_then(|parsed| self.local_to_utc(&parsed))
     }
 
     // Mon dd, yyyy, hh:mm:ss
@@ -434,37 +2302,43 @@ where
     // - September 17, 2012 10:09am
     // - September 17, 2012, 10:10:09
     fn month_mdy_hms(&self, input: &str) -> Option<Result<DateTime<Utc>>> {
-        lazy_static! {
-            static ref RE: Regex = Regex::new(
+        static RE: Lazy<Regex> = Lazy::new(|| {
+            Regex::new(
                 r"^[a-zA-Z]{3,9}\.?\s+[0-9]{1,2},\s+[0-9]{2,4},?\s+[0-9]{1,2}:[0-9]{2}(:[0-9]{2})?\s*(am|pm|AM|PM)?$",
-            ).unwrap();
-        }
+            )
+            .unwrap()
+        });
         if !RE.is_match(input) {
             return None;
         }
 
-        let dt = input.replace(", ", " ").replace(". ", " ");
-        self.tz
-            .datetime_from_str(&dt, "%B %d %Y %H:%M:%S")
-            .or_else(|_| self.tz.datetime_from_str(&dt, "%B %d %Y %H:%M"))
-            .or_else(|_| self.tz.datetime_from_str(&dt, "%B %d %Y %I:%M:%S %P"))
-            .or_else(|_| self.tz.datetime_from_str(&dt, "%B %d %Y %I:%M %P"))
+        let dt = replace(replace(Cow::Borrowed(input), ", ", " "), ". ", " ");
+        let parse_from_str = NaiveDateTime::parse_from_str;
+        parse_from_str(&dt, "%B %d %Y %H:%M:%S")
+            .or_else(|_| parse_from_str(&dt, "%B %d %Y %H:%M"))
+            .or_else(|err| {
+                self.twelve_hour(err, || {
+                    parse_from_str(&dt, "%B %d %Y %I:%M:%S %P")
+                        .or_else(|_| parse_from_str(&dt, "%B %d %Y %I:%M %P"))
+                })
+            })
             .ok()
-            .map(|at_tz| at_tz.with_timezone(&Utc))
-            .map(Ok)
+            .and_then(|parsed| self.local_to_utc(&parsed))
     }
 
     // Mon dd, yyyy hh:mm:ss z
     // - May 02, 2021 15:51:31 UTC
     // - May 02, 2021 15:51 UTC
+    // - May 02, 2021 15:51:31.123456 UTC
     // - May 26, 2021, 12:49 AM PDT
-    // - September 17, 2012 at 10:09am PST
+    // - September 17, 2012 10:09am PST
     fn month_mdy_hms_z(&self, input: &str) -> Option<Result<DateTime<Utc>>> {
-        lazy_static! {
-            static ref RE: Regex = Regex::new(
-                r"^[a-zA-Z]{3,9}\s+[0-9]{1,2},?\s+[0-9]{4}\s*,?(at)?\s+[0-9]{2}:[0-9]{2}(:[0-9]{2})?\s*(am|pm|AM|PM)?(?P<tz>\s+[+-:a-zA-Z0-9]{3,6})$",
-            ).unwrap();
-        }
+        static RE: Lazy<Regex> = Lazy::new(|| {
+            Regex::new(
+                r"^[a-zA-Z]{3,9}\s+[0-9]{1,2},?\s+[0-9]{4}\s*,?\s+[0-9]{2}:[0-9]{2}(:[0-9]{2})?(\.[0-9]{1,9})?\s*(am|pm|AM|PM)?(?P<tz>\s+[+-:a-zA-Z0-9]{3,9})$",
+            )
+            .unwrap()
+        });
         if !RE.is_match(input) {
             return None;
         }
@@ -474,11 +2348,16 @@ where
                 let parse_from_str = NaiveDateTime::parse_from_str;
                 return match timezone::parse(matched_tz.as_str().trim()) {
                     Ok(offset) => {
-                        let dt = input.replace(',', "").replace("at", "");
+                        let dt = replace(Cow::Borrowed(input), ",", "");
                         parse_from_str(&dt, "%B %d %Y %H:%M:%S %Z")
                             .or_else(|_| parse_from_str(&dt, "%B %d %Y %H:%M %Z"))
-                            .or_else(|_| parse_from_str(&dt, "%B %d %Y %I:%M:%S %P %Z"))
-                            .or_else(|_| parse_from_str(&dt, "%B %d %Y %I:%M %P %Z"))
+                            .or_else(|_| parse_from_str(&dt, "%B %d %Y %H:%M:%S%.f %Z"))
+                            .or_else(|err| {
+                                self.twelve_hour(err, || {
+                                    parse_from_str(&dt, "%B %d %Y %I:%M:%S %P %Z")
+                                        .or_else(|_| parse_from_str(&dt, "%B %d %Y %I:%M %P %Z"))
+                                })
+                            })
                             .ok()
                             .and_then(|parsed| offset.from_local_datetime(&parsed).single())
                             .map(|datetime| datetime.with_timezone(&Utc))
@@ -498,29 +2377,22 @@ where
     // - oct. 7, 1970
     // - oct. 7, 70
     // - October 7, 1970
-    fn month_mdy(&self, input: &str) -> Option<Result<DateTime<Utc>>> {
-        lazy_static! {
-            static ref RE: Regex =
-                Regex::new(r"^[a-zA-Z]{3,9}\.?\s+[0-9]{1,2},\s+[0-9]{2,4}$").unwrap();
-        }
+    fn month_mdy(&self, input: &str, now: &DateTime<Utc>) -> Option<Result<DateTime<Utc>>> {
+        static RE: Lazy<Regex> =
+            Lazy::new(|| Regex::new(r"^[a-zA-Z]{3,9}\.?\s+[0-9]{1,2},\s+[0-9]{2,4}$").unwrap());
         if !RE.is_match(input) {
             return None;
         }
 
         // set time to use
-        let time = match self.default_time {
-            Some(v) => v,
-            None => Utc::now().with_timezone(self.tz).time(),
-        };
+        let time = self.date_only_time(&now.with_timezone(&*self.tz));
 
-        let dt = input.replace(", ", " ").replace(". ", " ");
+        let dt = replace(replace(Cow::Borrowed(input), ", ", " "), ". ", " ");
         NaiveDate::parse_from_str(&dt, "%B %d %y")
             .or_else(|_| NaiveDate::parse_from_str(&dt, "%B %d %Y"))
             .ok()
             .map(|parsed| parsed.and_time(time))
-            .and_then(|datetime| self.tz.from_local_datetime(&datetime).single())
-            .map(|at_tz| at_tz.with_timezone(&Utc))
-            .map(Ok)
+            .and_then(|datetime| self.local_to_utc(&datetime))
     }
 
     // dd Mon yyyy hh:mm:ss
@@ -528,25 +2400,29 @@ where
     // - 12 Feb 2006 19:17
     // - 14 May 2019 19:11:40.164
     fn month_dmy_hms(&self, input: &str) -> Option<Result<DateTime<Utc>>> {
-        lazy_static! {
-            static ref RE: Regex = Regex::new(
+        static RE: Lazy<Regex> = Lazy::new(|| {
+            Regex::new(
                 r"^[0-9]{1,2}\s+[a-zA-Z]{3,9}\s+[0-9]{2,4},?\s+[0-9]{1,2}:[0-9]{2}(:[0-9]{2})?(\.[0-9]{1,9})?$",
-            ).unwrap();
-        }
+            )
+            .unwrap()
+        });
         if !RE.is_match(input) {
             return None;
         }
 
-        let dt = input.replace(", ", " ");
-        self.tz
-            .datetime_from_str(&dt, "%d %B %Y %H:%M:%S")
-            .or_else(|_| self.tz.datetime_from_str(&dt, "%d %B %Y %H:%M"))
-            .or_else(|_| self.tz.datetime_from_str(&dt, "%d %B %Y %H:%M:%S%.f"))
-            .or_else(|_| self.tz.datetime_from_str(&dt, "%d %B %Y %I:%M:%S %P"))
-            .or_else(|_| self.tz.datetime_from_str(&dt, "%d %B %Y %I:%M %P"))
+        let dt = replace(Cow::Borrowed(input), ", ", " ");
+        let parse_from_str = NaiveDateTime::parse_from_str;
+        parse_from_str(&dt, "%d %B %Y %H:%M:%S")
+            .or_else(|_| parse_from_str(&dt, "%d %B %Y %H:%M"))
+            .or_else(|_| parse_from_str(&dt, "%d %B %Y %H:%M:%S%.f"))
+            .or_else(|err| {
+                self.twelve_hour(err, || {
+                    parse_from_str(&dt, "%d %B %Y %I:%M:%S %P")
+                        .or_else(|_| parse_from_str(&dt, "%d %B %Y %I:%M %P"))
+                })
+            })
             .ok()
-            .map(|at_tz| at_tz.with_timezone(&Utc))
-            .map(Ok)
+            .and_then(|parsed| self.local_to_utc(&parsed))
     }
 
     // dd Mon yyyy
@@ -554,28 +2430,21 @@ where
     // - 7 oct 1970
     // - 03 February 2013
     // - 1 July 2013
-    fn month_dmy(&self, input: &str) -> Option<Result<DateTime<Utc>>> {
-        lazy_static! {
-            static ref RE: Regex =
-                Regex::new(r"^[0-9]{1,2}\s+[a-zA-Z]{3,9}\s+[0-9]{2,4}$").unwrap();
-        }
+    fn month_dmy(&self, input: &str, now: &DateTime<Utc>) -> Option<Result<DateTime<Utc>>> {
+        static RE: Lazy<Regex> =
+            Lazy::new(|| Regex::new(r"^[0-9]{1,2}\s+[a-zA-Z]{3,9}\s+[0-9]{2,4}$").unwrap());
         if !RE.is_match(input) {
             return None;
         }
 
         // set time to use
-        let time = match self.default_time {
-            Some(v) => v,
-            None => Utc::now().with_timezone(self.tz).time(),
-        };
+        let time = self.date_only_time(&now.with_timezone(&*self.tz));
 
         NaiveDate::parse_from_str(input, "%d %B %y")
             .or_else(|_| NaiveDate::parse_from_str(input, "%d %B %Y"))
             .ok()
             .map(|parsed| parsed.and_time(time))
-            .and_then(|datetime| self.tz.from_local_datetime(&datetime).single())
-            .map(|at_tz| at_tz.with_timezone(&Utc))
-            .map(Ok)
+            .and_then(|datetime| self.local_to_utc(&datetime))
     }
 
     // mm/dd/yyyy hh:mm:ss
@@ -592,30 +2461,37 @@ where
     // - 03/19/2012 10:11:59
     // - 03/19/2012 10:11:59.3186369
     fn slash_mdy_hms(&self, input: &str) -> Option<Result<DateTime<Utc>>> {
-        lazy_static! {
-            static ref RE: Regex = Regex::new(
-                r"^[0-9]{1,2}/[0-9]{1,2}/[0-9]{2,4}\s+[0-9]{1,2}:[0-9]{2}(:[0-9]{2})?(\.[0-9]{1,9})?\s*(am|pm|AM|PM)?$"
+        static RE: Lazy<Regex> = Lazy::new(|| {
+            Regex::new(
+                r"^[0-9]{1,2}/[0-9]{1,2}/[0-9]{2,4}\s+[0-9]{1,2}:[0-9]{2}(:[0-9]{2})?(\.[0-9]{1,9})?\s*(am|pm|AM|PM)?$",
             )
-            .unwrap();
-        }
+            .unwrap()
+        });
         if !RE.is_match(input) {
             return None;
         }
 
-        self.tz
-            .datetime_from_str(input, "%m/%d/%y %H:%M:%S")
-            .or_else(|_| self.tz.datetime_from_str(input, "%m/%d/%y %H:%M"))
-            .or_else(|_| self.tz.datetime_from_str(input, "%m/%d/%y %H:%M:%S%.f"))
-            .or_else(|_| self.tz.datetime_from_str(input, "%m/%d/%y %I:%M:%S %P"))
-            .or_else(|_| self.tz.datetime_from_str(input, "%m/%d/%y %I:%M %P"))
-            .or_else(|_| self.tz.datetime_from_str(input, "%m/%d/%Y %H:%M:%S"))
-            .or_else(|_| self.tz.datetime_from_str(input, "%m/%d/%Y %H:%M"))
-            .or_else(|_| self.tz.datetime_from_str(input, "%m/%d/%Y %H:%M:%S%.f"))
-            .or_else(|_| self.tz.datetime_from_str(input, "%m/%d/%Y %I:%M:%S %P"))
-            .or_else(|_| self.tz.datetime_from_str(input, "%m/%d/%Y %I:%M %P"))
+        let parse_from_str = NaiveDateTime::parse_from_str;
+        parse_from_str(input, "%m/%d/%y %H:%M:%S")
+            .or_else(|_| parse_from_str(input, "%m/%d/%y %H:%M"))
+            .or_else(|_| parse_from_str(input, "%m/%d/%y %H:%M:%S%.f"))
+            .or_else(|err| {
+                self.twelve_hour(err, || {
+                    parse_from_str(input, "%m/%d/%y %I:%M:%S %P")
+                        .or_else(|_| parse_from_str(input, "%m/%d/%y %I:%M %P"))
+                })
+            })
+            .or_else(|_| parse_from_str(input, "%m/%d/%Y %H:%M:%S"))
+            .or_else(|_| parse_from_str(input, "%m/%d/%Y %H:%M"))
+            .or_else(|_| parse_from_str(input, "%m/%d/%Y %H:%M:%S%.f"))
+            .or_else(|err| {
+                self.twelve_hour(err, || {
+                    parse_from_str(input, "%m/%d/%Y %I:%M:%S %P")
+                        .or_else(|_| parse_from_str(input, "%m/%d/%Y %I:%M %P"))
+                })
+            })
             .ok()
-            .map(|at_tz| at_tz.with_timezone(&Utc))
-            .map(Ok)
+            .and_then(|parsed| self.local_to_utc(&parsed))
     }
 
     // mm/dd/yyyy
@@ -623,30 +2499,24 @@ where
     // - 03/31/2014
     // - 08/21/71
     // - 8/1/71
-    fn slash_mdy(&self, input: &str) -> Option<Result<DateTime<Utc>>> {
-        lazy_static! {
-            static ref RE: Regex = Regex::new(r"^[0-9]{1,2}/[0-9]{1,2}/[0-9]{2,4}$").unwrap();
-        }
+    fn slash_mdy(&self, input: &str, now: &DateTime<Utc>) -> Option<Result<DateTime<Utc>>> {
+        static RE: Lazy<Regex> =
+            Lazy::new(|| Regex::new(r"^[0-9]{1,2}/[0-9]{1,2}/[0-9]{2,4}$").unwrap());
         if !RE.is_match(input) {
             return None;
         }
 
         // set time to use
-        let time = match self.default_time {
-            Some(v) => v,
-            None => Utc::now().with_timezone(self.tz).time(),
-        };
+        let time = self.date_only_time(&now.with_timezone(&*self.tz));
 
         NaiveDate::parse_from_str(input, "%m/%d/%y")
             .or_else(|_| NaiveDate::parse_from_str(input, "%m/%d/%Y"))
             .ok()
             .map(|parsed| parsed.and_time(time))
-            .and_then(|datetime| self.tz.from_local_datetime(&datetime).single())
-            .map(|at_tz| at_tz.with_timezone(&Utc))
-            .map(Ok)
+            .and_then(|datetime| self.local_to_utc(&datetime))
     }
 
-    // yyyy/mm/dd hh:mm:ss
+    // yyyy/mm/dd hh:mm:ss, or yyyy/dd/mm hh:mm:ss with `DateOrder::Ydm`
     // - 2014/4/8 22:05
     // - 2014/04/08 22:05
     // - 2014/04/2 03:00:51
@@ -654,50 +2524,48 @@ where
     // - 2012/03/19 10:11:59
     // - 2012/03/19 10:11:59.3186369
     fn slash_ymd_hms(&self, input: &str) -> Option<Result<DateTime<Utc>>> {
-        lazy_static! {
-            static ref RE: Regex = Regex::new(
-                r"^[0-9]{4}/[0-9]{1,2}/[0-9]{1,2}\s+[0-9]{1,2}:[0-9]{2}(:[0-9]{2})?(\.[0-9]{1,9})?\s*(am|pm|AM|PM)?$"
+        static RE: Lazy<Regex> = Lazy::new(|| {
+            Regex::new(
+                r"^[0-9]{4}/[0-9]{1,2}/[0-9]{1,2}\s+[0-9]{1,2}:[0-9]{2}(:[0-9]{2})?(\.[0-9]{1,9})?\s*(am|pm|AM|PM)?$",
             )
-            .unwrap();
-        }
+            .unwrap()
+        });
         if !RE.is_match(input) {
             return None;
         }
 
-        self.tz
-            .datetime_from_str(input, "%Y/%m/%d %H:%M:%S")
-            .or_else(|_| self.tz.datetime_from_str(input, "%Y/%m/%d %H:%M"))
-            .or_else(|_| self.tz.datetime_from_str(input, "%Y/%m/%d %H:%M:%S%.f"))
-            .or_else(|_| self.tz.datetime_from_str(input, "%Y/%m/%d %I:%M:%S %P"))
-            .or_else(|_| self.tz.datetime_from_str(input, "%Y/%m/%d %I:%M %P"))
+        let ymd = self.slash_year_first();
+        let parse_from_str =
+            |time: &str| NaiveDateTime::parse_from_str(input, &format!("{} {}", ymd, time));
+        parse_from_str("%H:%M:%S")
+            .or_else(|_| parse_from_str("%H:%M"))
+            .or_else(|_| parse_from_str("%H:%M:%S%.f"))
+            .or_else(|err| {
+                self.twelve_hour(err, || {
+                    parse_from_str("%I:%M:%S %P").or_else(|_| parse_from_str("%I:%M %P"))
+                })
+            })
             .ok()
-            .map(|at_tz| at_tz.with_timezone(&Utc))
-            .map(Ok)
+            .and_then(|parsed| self.local_to_utc(&parsed))
     }
 
-    // yyyy/mm/dd
+    // yyyy/mm/dd, or yyyy/dd/mm with `DateOrder::Ydm`
     // - 2014/3/31
     // - 2014/03/31
-    fn slash_ymd(&self, input: &str) -> Option<Result<DateTime<Utc>>> {
-        lazy_static! {
-            static ref RE: Regex = Regex::new(r"^[0-9]{4}/[0-9]{1,2}/[0-9]{1,2}$").unwrap();
-        }
+    fn slash_ymd(&self, input: &str, now: &DateTime<Utc>) -> Option<Result<DateTime<Utc>>> {
+        static RE: Lazy<Regex> =
+            Lazy::new(|| Regex::new(r"^[0-9]{4}/[0-9]{1,2}/[0-9]{1,2}$").unwrap());
         if !RE.is_match(input) {
             return None;
         }
 
         // set time to use
-        let time = match self.default_time {
-            Some(v) => v,
-            None => Utc::now().with_timezone(self.tz).time(),
-        };
+        let time = self.date_only_time(&now.with_timezone(&*self.tz));
 
-        NaiveDate::parse_from_str(input, "%Y/%m/%d")
+        NaiveDate::parse_from_str(input, self.slash_year_first())
             .ok()
             .map(|parsed| parsed.and_time(time))
-            .and_then(|datetime| self.tz.from_local_datetime(&datetime).single())
-            .map(|at_tz| at_tz.with_timezone(&Utc))
-            .map(Ok)
+            .and_then(|datetime| self.local_to_utc(&datetime))
     }
 
     // mm.dd.yyyy
@@ -707,97 +2575,376 @@ where
     // yyyy.mm.dd
     // - 2014.03.30
     // - 2014.03
-    fn dot_mdy_or_ymd(&self, input: &str) -> Option<Result<DateTime<Utc>>> {
-        lazy_static! {
-            static ref RE: Regex = Regex::new(r"[0-9]{1,4}.[0-9]{1,4}[0-9]{1,4}").unwrap();
-        }
+    fn dot_mdy_or_ymd(&self, input: &str, now: &DateTime<Utc>) -> Option<Result<DateTime<Utc>>> {
+        static RE: Lazy<Regex> =
+            Lazy::new(|| Regex::new(r"^[0-9]{1,4}\.[0-9]{1,2}(\.[0-9]{1,4})?$").unwrap());
         if !RE.is_match(input) {
             return None;
         }
 
         // set time to use
-        let time = match self.default_time {
-            Some(v) => v,
-            None => Utc::now().with_timezone(self.tz).time(),
-        };
+        let time = self.date_only_time(&now.with_timezone(&*self.tz));
 
         NaiveDate::parse_from_str(input, "%m.%d.%y")
             .or_else(|_| NaiveDate::parse_from_str(input, "%m.%d.%Y"))
             .or_else(|_| NaiveDate::parse_from_str(input, "%Y.%m.%d"))
-            .or_else(|_| {
-                NaiveDate::parse_from_str(&format!("{}.{}", input, Utc::now().day()), "%Y.%m.%d")
-            })
+            .or_else(|_| NaiveDate::parse_from_str(&format!("{}.1", input), "%Y.%m.%d"))
             .ok()
             .map(|parsed| parsed.and_time(time))
-            .and_then(|datetime| self.tz.from_local_datetime(&datetime).single())
-            .map(|at_tz| at_tz.with_timezone(&Utc))
-            .map(Ok)
+            .and_then(|datetime| self.local_to_utc(&datetime))
     }
 
     // yymmdd hh:mm:ss mysql log
     // - 171113 14:14:20
     fn mysql_log_timestamp(&self, input: &str) -> Option<Result<DateTime<Utc>>> {
-        lazy_static! {
-            static ref RE: Regex = Regex::new(r"[0-9]{6}\s+[0-9]{2}:[0-9]{2}:[0-9]{2}").unwrap();
-        }
+        static RE: Lazy<Regex> =
+            Lazy::new(|| Regex::new(r"^[0-9]{6}\s+[0-9]{2}:[0-9]{2}:[0-9]{2}$").unwrap());
         if !RE.is_match(input) {
             return None;
         }
 
-        self.tz
-            .datetime_from_str(input, "%y%m%d %H:%M:%S")
+        let parse_from_str = NaiveDateTime::parse_from_str;
+        parse_from_str(input, "%y%m%d %H:%M:%S")
             .ok()
-            .map(|at_tz| at_tz.with_timezone(&Utc))
-            .map(Ok)
+            .and_then(|parsed| self.local_to_utc(&parsed))
     }
 
     // chinese yyyy mm dd hh mm ss
     // - 2014年04月08日11时25分18秒
+    // - 2014年04月08日 11时25分18秒
+    // - 2014年04月08日11时25分18.5秒
+    // - 2014年 4月 8日 11時25分18秒
     fn chinese_ymd_hms(&self, input: &str) -> Option<Result<DateTime<Utc>>> {
-        lazy_static! {
-            static ref RE: Regex =
-                Regex::new(r"^[0-9]{4}年[0-9]{2}月[0-9]{2}日[0-9]{2}时[0-9]{2}分[0-9]{2}秒$")
-                    .unwrap();
-        }
-        if !RE.is_match(input) {
+        static RE: Lazy<Regex> = Lazy::new(|| {
+            Regex::new(
+                r"^(?P<year>[0-9]{4})\s*年\s*(?P<month>[0-9]{1,2})\s*月\s*(?P<day>[0-9]{1,2})\s*日\s*(?P<hour>[0-9]{1,2})\s*[时時]\s*(?P<minute>[0-9]{1,2})\s*分\s*(?P<second>[0-9]{1,2}(\.[0-9]{1,9})?)\s*秒$",
+            )
+            .unwrap()
+        });
+        let caps = RE.captures(input)?;
+
+        cjk_date(&caps)
+            .zip(cjk_time(&caps))
+            .map(|(date, time)| date.and_time(time))
+            .and_then(|datetime| self.local_to_utc(&datetime))
+    }
+
+    // chinese yyyy mm dd 上午/下午 hh mm ss
+    // - 2014年04月08日上午11时25分18秒
+    // - 2014年04月08日 下午3时25分
+    // - 2014年04月08日下午3时
+    fn chinese_ymd_meridiem_hms(&self, input: &str) -> Option<Result<DateTime<Utc>>> {
+        static RE: Lazy<Regex> = Lazy::new(|| {
+            Regex::new(
+                r"^(?P<year>[0-9]{4})\s*年\s*(?P<month>[0-9]{1,2})\s*月\s*(?P<day>[0-9]{1,2})\s*日\s*(?P<meridiem>上午|下午)(?P<hour>[0-9]{1,2})[时時]((?P<minute>[0-9]{1,2})分((?P<second>[0-9]{1,2})秒)?)?$",
+            )
+            .unwrap()
+        });
+        if !self.allow_12_hour {
             return None;
         }
+        let caps = RE.captures(input)?;
 
-        self.tz
-            .datetime_from_str(input, "%Y年%m月%d日%H时%M分%S秒")
-            .ok()
-            .map(|at_tz| at_tz.with_timezone(&Utc))
-            .map(Ok)
+        let meridiem = match &caps["meridiem"] {
+            "上午" => "AM",
+            _ => "PM",
+        };
+        let time = format!(
+            "{}:{}:{} {}",
+            &caps["hour"],
+            caps.name("minute").map_or("0", |m| m.as_str()),
+            caps.name("second").map_or("0", |s| s.as_str()),
+            meridiem
+        );
+        cjk_date(&caps)
+            .zip(NaiveTime::parse_from_str(&time, "%I:%M:%S %P").ok())
+            .map(|(date, time)| date.and_time(time))
+            .and_then(|datetime| self.local_to_utc(&datetime))
     }
 
     // chinese yyyy mm dd
     // - 2014年04月08日
-    fn chinese_ymd(&self, input: &str) -> Option<Result<DateTime<Utc>>> {
-        lazy_static! {
-            static ref RE: Regex = Regex::new(r"^[0-9]{4}年[0-9]{2}月[0-9]{2}日$").unwrap();
-        }
-        if !RE.is_match(input) {
-            return None;
-        }
+    // - 2014年 4月 8日
+    fn chinese_ymd(&self, input: &str, now: &DateTime<Utc>) -> Option<Result<DateTime<Utc>>> {
+        static RE: Lazy<Regex> = Lazy::new(|| {
+            Regex::new(
+                r"^(?P<year>[0-9]{4})\s*年\s*(?P<month>[0-9]{1,2})\s*月\s*(?P<day>[0-9]{1,2})\s*日$",
+            )
+            .unwrap()
+        });
+        let caps = RE.captures(input)?;
 
         // set time to use
-        let time = match self.default_time {
-            Some(v) => v,
-            None => Utc::now().with_timezone(self.tz).time(),
-        };
+        let time = self.date_only_time(&now.with_timezone(&*self.tz));
 
-        NaiveDate::parse_from_str(input, "%Y年%m月%d日")
-            .ok()
+        cjk_date(&caps)
             .map(|parsed| parsed.and_time(time))
-            .and_then(|datetime| self.tz.from_local_datetime(&datetime).single())
-            .map(|at_tz| at_tz.with_timezone(&Utc))
-            .map(Ok)
+            .and_then(|datetime| self.local_to_utc(&datetime))
+    }
+
+    // korean yyyy mm dd hh mm ss
+    // - 2014년04월08일 11시25분18초
+    // - 2014년 4월 8일 11시 25분 18.5초
+    fn korean_ymd_hms(&self, input: &str) -> Option<Result<DateTime<Utc>>> {
+        static RE: Lazy<Regex> = Lazy::new(|| {
+            Regex::new(
+                r"^(?P<year>[0-9]{4})\s*년\s*(?P<month>[0-9]{1,2})\s*월\s*(?P<day>[0-9]{1,2})\s*일\s*(?P<hour>[0-9]{1,2})\s*시\s*(?P<minute>[0-9]{1,2})\s*분\s*(?P<second>[0-9]{1,2}(\.[0-9]{1,9})?)\s*초$",
+            )
+            .unwrap()
+        });
+        let caps = RE.captures(input)?;
+
+        cjk_date(&caps)
+            .zip(cjk_time(&caps))
+            .map(|(date, time)| date.and_time(time))
+            .and_then(|datetime| self.local_to_utc(&datetime))
+    }
+
+    // korean yyyy mm dd
+    // - 2014년04월08일
+    // - 2014년 4월 8일
+    fn korean_ymd(&self, input: &str, now: &DateTime<Utc>) -> Option<Result<DateTime<Utc>>> {
+        static RE: Lazy<Regex> = Lazy::new(|| {
+            Regex::new(
+                r"^(?P<year>[0-9]{4})\s*년\s*(?P<month>[0-9]{1,2})\s*월\s*(?P<day>[0-9]{1,2})\s*일$",
+            )
+            .unwrap()
+        });
+        let caps = RE.captures(input)?;
+
+        // set time to use
+        let time = self.date_only_time(&now.with_timezone(&*self.tz));
+
+        cjk_date(&caps)
+            .map(|parsed| parsed.and_time(time))
+            .and_then(|datetime| self.local_to_utc(&datetime))
+    }
+}
+
+// Reads the date from the `year`, `month` and `day` groups of a Chinese, Japanese or Korean
+// format, which only differ in the markers between the numbers.
+fn cjk_date(caps: &Captures) -> Option<NaiveDate> {
+    NaiveDate::from_ymd_opt(
+        caps["year"].parse().ok()?,
+        caps["month"].parse().ok()?,
+        caps["day"].parse().ok()?,
+    )
+}
+
+// Reads the time from the `hour`, `minute` and `second` groups of a Chinese, Japanese or Korean
+// format. The seconds may have a fraction.
+fn cjk_time(caps: &Captures) -> Option<NaiveTime> {
+    let time = format!("{}:{}:{}", &caps["hour"], &caps["minute"], &caps["second"]);
+    NaiveTime::parse_from_str(&time, "%H:%M:%S%.f").ok()
+}
+
+/// Cleans up the parts of an input that are not part of any accepted format before it is
+/// dispatched to the parsers:
+///
+/// - a trailing description in parentheses, e.g. `2017-11-25 13:31:15 PST (Pacific Standard
+///   Time)`, is stripped, unless it is an offset, such as `(UTC+02:00)`.
+/// - a run of whitespace, a tab or a line break between the words, e.g. in
+///   `Wed,  02 Jun  2021 06:31:39 GMT`, is written as a single space, as none of the accepted
+///   formats tells anything apart by the amount of whitespace.
+/// - fractional seconds with more than 9 digits, e.g. `17:24:37.123456789012`, are truncated,
+///   not rounded, to the 9 digits of nanosecond precision that chrono can parse.
+/// - a standalone `at` between the date and the time, e.g. `September 17, 2012 at 10:09am`, is
+///   replaced with a space. An `at` inside of a word is kept.
+/// - a time written with dots, e.g. `2012-03-19 10.11.59`, is written with colons instead, but only
+///   right after a `yyyy-mm-dd`, `yyyy/mm/dd` or `mm/dd/yyyy` date and a space, so that a dotted
+///   date, such as `2014.03.30`, is left alone.
+/// - the end of a day written as `24:00:00` after a `yyyy-mm-dd` date, as ISO 8601 allows, e.g.
+///   `2021-05-01T24:00:00Z`, is written as `00:00:00` of the next day, because chrono does not
+///   accept hour 24. Any other time in hour 24, such as `24:00:01`, is left to fail.
+/// - a leading weekday before a `Mon dd yyyy` date, e.g. `Wed Jun 02 2021 06:31:39 GMT-0700` from
+///   JavaScript's `Date.toString()`, is stripped, as the date already tells the weekday.
+/// - a Chinese weekday, e.g. `星期二` in `2014年04月08日 星期二 下午3时`, is stripped wherever it
+///   is, for the same reason.
+/// - a day written in prose before the month name, e.g. `the 3rd of July, 2013`, is written as
+///   `3 July 2013`, by stripping a leading `the`, the ordinal suffix of the day, an `of` between
+///   the day and the month name and a comma after the month name.
+/// - a Portuguese date, e.g. `8 de maio de 2009`, is written as `8 May 2009`, by stripping the
+///   `de` between the day, the month name and the year, and reading the month name in English.
+/// - a leading `AM` or `PM` before a time, e.g. `PM 5:57` from some legacy systems, is moved to
+///   right after the time, as in `5:57 PM`.
+fn normalize(input: &str) -> Cow<'_, str> {
+    static DESCRIPTION: Lazy<Regex> =
+        Lazy::new(|| Regex::new(r"^(?P<datetime>.*\S)\s+\((?P<description>[^()]*)\)$").unwrap());
+    static OFFSET: Lazy<Regex> = Lazy::new(|| Regex::new(r"^(UTC|GMT)?[+-][0-9]").unwrap());
+    static WHITESPACE: Lazy<Regex> =
+        Lazy::new(|| Regex::new(r"(?-u:[ \t\n\x0C\r]{2,}|[\t\n\x0C\r])").unwrap());
+    static AT: Lazy<Regex> = Lazy::new(|| Regex::new(r"\s+at\s+").unwrap());
+    static FRACTION: Lazy<Regex> =
+        Lazy::new(|| Regex::new(r"(:[0-9]{2}\.[0-9]{9})[0-9]+").unwrap());
+    static DOTTED_TIME: Lazy<Regex> = Lazy::new(|| {
+        Regex::new(
+            r"^(?P<date>([0-9]{4}-[0-9]{2}-[0-9]{2}|[0-9]{4}/[0-9]{1,2}/[0-9]{1,2}|[0-9]{1,2}/[0-9]{1,2}/[0-9]{2,4})\s+)(?P<time>[0-9]{1,2}\.[0-9]{2}(\.[0-9]{2})?)(?P<rest>([\s+-].*|(?i:am|pm).*)?)$",
+        )
+        .unwrap()
+    });
+    static END_OF_DAY: Lazy<Regex> = Lazy::new(|| {
+        Regex::new(
+            r"^(?P<date>[0-9]{4}-[0-9]{2}-[0-9]{2})(?P<separator>[Tt]|\s+)24:00(?P<seconds>:00(\.0{1,9})?)?(?P<rest>([^0-9.:].*)?)$",
+        )
+        .unwrap()
+    });
+    static WEEKDAY: Lazy<Regex> = Lazy::new(|| {
+        Regex::new(
+            r"^(?i:mon|tue|wed|thu|fri|sat|sun)[a-z]*,?\s+(?P<date>[a-zA-Z]{3,9}\.?\s+[0-9]{1,2},?\s+[0-9]{4}\b)",
+        )
+        .unwrap()
+    });
+    static CHINESE_WEEKDAY: Lazy<Regex> =
+        Lazy::new(|| Regex::new(r"\s*星期[一二三四五六日天]\s*").unwrap());
+    static DAY_OF_MONTH: Lazy<Regex> = Lazy::new(|| {
+        Regex::new(
+            r"^(?i)(the\s+)?(?P<day>[0-9]{1,2})(st|nd|rd|th)?(\s+of)?\s+(?P<month>(jan|feb|mar|apr|may|jun|jul|aug|sep|oct|nov|dec)[a-z]*)\b\.?,?",
+        )
+        .unwrap()
+    });
+    static LEADING_MERIDIEM: Lazy<Regex> = Lazy::new(|| {
+        Regex::new(
+            r"^(?P<meridiem>(?i:am|pm))\s+(?P<time>[0-9]{1,2}(:[0-9]{2}){0,2}(\.[0-9]{1,9})?)\b",
+        )
+        .unwrap()
+    });
+    static PORTUGUESE_DATE: Lazy<Regex> = Lazy::new(|| {
+        Regex::new(
+            r"^(?P<day>[0-9]{1,2})\s+(?i:de)\s+(?P<month>\w+)\s+(?i:de)\s+(?P<year>[0-9]{4})\b",
+        )
+        .unwrap()
+    });
+
+    let mut normalized = Cow::Borrowed(input);
+    if input.ends_with(')') {
+        if let Some(caps) = DESCRIPTION.captures(input) {
+            if !OFFSET.is_match(&caps["description"]) {
+                normalized = Cow::Borrowed(caps.name("datetime").unwrap().as_str());
+            }
+        }
+    }
+    let normalized = replace_regex(normalized, &WHITESPACE, " ");
+    let normalized = replace_regex(normalized, &AT, " ");
+    let mut normalized = replace_regex(normalized, &FRACTION, "${1}");
+    if let Some(caps) = DOTTED_TIME.captures(&normalized) {
+        let time = caps["time"].replace('.', ":");
+        normalized = Cow::Owned(format!("{}{}{}", &caps["date"], time, &caps["rest"]));
+    }
+    if let Some(caps) = END_OF_DAY.captures(&normalized) {
+        if let Some(next_day) = NaiveDate::parse_from_str(&caps["date"], "%Y-%m-%d")
+            .ok()
+            .and_then(|date| date.succ_opt())
+        {
+            normalized = Cow::Owned(format!(
+                "{}{}00:00{}{}",
+                next_day.format("%Y-%m-%d"),
+                &caps["separator"],
+                caps.name("seconds").map_or("", |m| m.as_str()),
+                &caps["rest"]
+            ));
+        }
+    }
+    let normalized = replace_regex(normalized, &WEEKDAY, "${date}");
+    let normalized = replace_regex(normalized, &CHINESE_WEEKDAY, "");
+    let mut normalized = replace_regex(normalized, &DAY_OF_MONTH, "${day} ${month}");
+    if let Some(caps) = PORTUGUESE_DATE.captures(&normalized) {
+        let month = caps["month"].to_lowercase();
+        if let Some((_, english)) = PORTUGUESE_MONTHS.iter().find(|(name, _)| *name == month) {
+            normalized = Cow::Owned(format!(
+                "{} {} {}{}",
+                &caps["day"],
+                english,
+                &caps["year"],
+                &normalized[caps.get(0).unwrap().end()..]
+            ));
+        }
+    }
+    replace_regex(normalized, &LEADING_MERIDIEM, "${time} ${meridiem}")
+}
+
+/// Replaces all matches of `from` with `to`, and only allocates a new string when `input` actually
+/// contains `from`.
+fn replace<'a>(input: Cow<'a, str>, from: &str, to: &str) -> Cow<'a, str> {
+    if input.contains(from) {
+        Cow::Owned(input.replace(from, to))
+    } else {
+        input
+    }
+}
+
+/// Reports the `yyyy-mm-dd` date at the start of an input, which matched one of the `yyyy-mm-dd`
+/// formats but could not be parsed, as [`ParseError::InvalidDate`] when the date itself does not
+/// exist. Otherwise, the input is left to the next parser.
+fn invalid_ymd(input: &str) -> Option<Result<DateTime<Utc>>> {
+    let date = input.get(..10)?;
+    match NaiveDate::parse_from_str(date, "%Y-%m-%d") {
+        Ok(_) => None,
+        Err(_) => Some(Err(ParseError::InvalidDate(date.to_string()).into())),
+    }
+}
+
+/// Same as [`replace`], but replaces all matches of a regex.
+fn replace_regex<'a>(input: Cow<'a, str>, re: &Regex, to: &str) -> Cow<'a, str> {
+    let replaced = match re.replace_all(&input, to) {
+        Cow::Owned(replaced) => Some(replaced),
+        Cow::Borrowed(_) => None,
+    };
+    match replaced {
+        Some(replaced) => Cow::Owned(replaced),
+        None => input,
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use chrono_tz::America::New_York;
+
+    #[test]
+    fn custom() {
+        let parse = Parse::new(&Utc, Some(NaiveTime::from_hms(0, 0, 0)))
+            .custom_formats(&["%d|%m|%Y %H:%M", "%d|%m|%Y %H:%M %z"])
+            .custom_date_formats(&["%d|%m|%Y"]);
+        let now = Utc::now();
+
+        let test_cases = [
+            ("08|05|2021", Utc.ymd(2021, 5, 8).and_hms(0, 0, 0)),
+            ("08|05|2021 10:30", Utc.ymd(2021, 5, 8).and_hms(10, 30, 0)),
+            (
+                "08|05|2021 10:30 +0200",
+                Utc.ymd(2021, 5, 8).and_hms(8, 30, 0),
+            ),
+        ];
+
+        for &(input, want) in test_cases.iter() {
+            assert_eq!(
+                parse.custom(input, &now).unwrap().unwrap(),
+                want,
+                "custom/{}",
+                input
+            );
+            assert_eq!(parse.parse(input).unwrap(), want, "custom/parse/{}", input);
+        }
+
+        // a format without an offset is read in the parsing timezone
+        let tz = FixedOffset::west(8 * 3600);
+        let in_tz = Parse::new(&tz, None).custom_formats(&["%d|%m|%Y %H:%M"]);
+        assert_eq!(
+            in_tz.custom("08|05|2021 10:30", &now).unwrap().unwrap(),
+            Utc.ymd(2021, 5, 8).and_hms(18, 30, 0)
+        );
+
+        // the custom formats are tried before the accepted ones
+        let day_first = Parse::new(&Utc, None).custom_formats(&["%d/%m/%Y %H:%M:%S"]);
+        assert_eq!(
+            day_first.parse("03/04/2012 10:11:59").unwrap(),
+            Utc.ymd(2012, 4, 3).and_hms(10, 11, 59)
+        );
+
+        assert!(parse.custom("08|13|2021", &now).is_none());
+        assert!(parse.custom("2021-05-08", &now).is_none());
+        assert!(Parse::new(&Utc, None).custom("08|05|2021", &now).is_none());
+    }
 
     #[test]
     fn unix_timestamp() {
@@ -816,6 +2963,27 @@ mod tests {
                 "1620024872717915000",
                 Utc.ymd(2021, 5, 3).and_hms_nano(6, 54, 32, 717915000),
             ),
+            (
+                "1620024872717915",
+                Utc.ymd(2021, 5, 3).and_hms_micro(6, 54, 32, 717915),
+            ),
+            // other lengths are read in the closest unit with a plausible year
+            (
+                "16200218484",
+                Utc.ymd(1970, 7, 7).and_hms_milli(12, 3, 38, 484),
+            ),
+            (
+                "162002184842",
+                Utc.ymd(1975, 2, 19).and_hms_milli(0, 36, 24, 842),
+            ),
+            (
+                "16200248727179",
+                Utc.ymd(1970, 7, 7).and_hms_micro(12, 4, 8, 727179),
+            ),
+            (
+                "162002487271791500",
+                Utc.ymd(1975, 2, 19).and_hms_nano(0, 41, 27, 271791500),
+            ),
         ];
 
         for &(input, want) in test_cases.iter() {
@@ -826,26 +2994,265 @@ mod tests {
                 input
             )
         }
+        assert_eq!(
+            parse.unix_timestamp("@1620021848").unwrap().unwrap(),
+            Utc.ymd(2021, 5, 3).and_hms(6, 4, 8)
+        );
+        assert_eq!(
+            parse.unix_timestamp("@0").unwrap().unwrap(),
+            Utc.ymd(1970, 1, 1).and_hms(0, 0, 0)
+        );
+        assert_eq!(
+            parse.unix_timestamp("@-86400").unwrap().unwrap(),
+            Utc.ymd(1969, 12, 31).and_hms(0, 0, 0)
+        );
+        // `@` is always followed by seconds, whatever the number of digits
+        assert_eq!(
+            parse.unix_timestamp("@1620021848429").unwrap().unwrap(),
+            Utc.timestamp(1620021848429, 0)
+        );
+        for input in ["epoch", "Epoch", "EPOCH"] {
+            assert_eq!(
+                parse.unix_timestamp(input).unwrap().unwrap(),
+                Utc.ymd(1970, 1, 1).and_hms(0, 0, 0),
+                "unix_timestamp/{}",
+                input
+            );
+        }
+        assert!(parse.unix_timestamp("@").is_none());
+        assert!(parse.unix_timestamp("@-").is_none());
+        assert!(parse.unix_timestamp("@1620021848s").is_none());
+        assert!(parse.unix_timestamp("@ 1620021848").is_none());
+        assert!(parse.unix_timestamp("epochs").is_none());
+        assert_eq!(
+            parse.unix_timestamp("1_620_021_848").unwrap().unwrap(),
+            Utc.ymd(2021, 5, 3).and_hms(6, 4, 8)
+        );
+        assert_eq!(
+            parse.unix_timestamp("1620_021_848_429").unwrap().unwrap(),
+            Utc.ymd(2021, 5, 3).and_hms_milli(6, 4, 8, 429)
+        );
+        for input in [
+            "_1620",
+            "16__20",
+            "_1620021848",
+            "1620021848_",
+            "16__20021848",
+            "_",
+        ] {
+            assert!(
+                parse.unix_timestamp(input).is_none(),
+                "unix_timestamp/{}",
+                input
+            );
+        }
+        // parse() goes through the same closest plausible unit
+        for (input, want) in [
+            (
+                "16200218484",
+                Utc.ymd(1970, 7, 7).and_hms_milli(12, 3, 38, 484),
+            ),
+            (
+                "16200248727179",
+                Utc.ymd(1970, 7, 7).and_hms_micro(12, 4, 8, 727179),
+            ),
+            (
+                "1620024872717915",
+                Utc.ymd(2021, 5, 3).and_hms_micro(6, 54, 32, 717915),
+            ),
+        ] {
+            assert_eq!(
+                parse.parse(input).unwrap(),
+                want,
+                "unix_timestamp/parse/{}",
+                input
+            );
+        }
+        // a compact datetime is not read as a timestamp in any unit
+        for input in ["20210501101112", "202105011011"] {
+            assert!(
+                parse.unix_timestamp(input).is_none(),
+                "unix_timestamp/{}",
+                input
+            );
+            assert!(
+                parse.parse(input).is_err(),
+                "unix_timestamp/parse/{}",
+                input
+            );
+        }
+        // ten characters, but only nine digits
+        assert!(parse.unix_timestamp("162_002_18").is_none());
         assert!(parse.unix_timestamp("15116").is_none());
+        // seconds until the year 2286 are still read as seconds
+        assert_eq!(
+            parse.unix_timestamp("9999999999").unwrap().unwrap(),
+            Utc.ymd(2286, 11, 20).and_hms(17, 46, 39)
+        );
         assert!(parse
             .unix_timestamp("16200248727179150001620024872717915000")
             .is_none());
         assert!(parse.unix_timestamp("not-a-ts").is_none());
+        assert!(parse.unix_timestamp("+151164854").is_none());
+        assert!(parse.unix_timestamp("1511648546 ").is_none());
+        assert!(parse.unix_timestamp("151164854٦").is_none());
     }
 
     #[test]
-    fn rfc3339() {
+    fn unix_timestamp_unit() {
         let parse = Parse::new(&Utc, None);
 
         let test_cases = [
+            ("1620021848s", Utc.ymd(2021, 5, 3).and_hms(6, 4, 8)),
+            ("0s", Utc.ymd(1970, 1, 1).and_hms(0, 0, 0)),
             (
-                "2021-05-01T01:17:02.604456Z",
+                "1620021848429ms",
+                Utc.ymd(2021, 5, 3).and_hms_milli(6, 4, 8, 429),
+            ),
+            (
+                "1620024872717915us",
+                Utc.ymd(2021, 5, 3).and_hms_micro(6, 54, 32, 717915),
+            ),
+            (
+                "1620024872717915000ns",
+                Utc.ymd(2021, 5, 3).and_hms_nano(6, 54, 32, 717915000),
+            ),
+            (
+                "1620021848000ns",
+                Utc.ymd(1970, 1, 1).and_hms_micro(0, 27, 0, 21848),
+            ),
+        ];
+
+        for &(input, want) in test_cases.iter() {
+            assert_eq!(
+                parse.unix_timestamp_unit(input).unwrap().unwrap(),
+                want,
+                "unix_timestamp_unit/{}",
+                input
+            );
+            assert_eq!(parse.parse(input).unwrap(), want, "parse/{}", input);
+        }
+        assert!(parse.unix_timestamp_unit("1620021848").is_none());
+        assert!(parse.unix_timestamp_unit("1620021848m").is_none());
+        assert!(parse.unix_timestamp_unit("9999999999999999999s").is_none());
+        assert!(parse.unix_timestamp_unit("not-a-ts").is_none());
+    }
+
+    #[test]
+    fn unix_timestamp_with_epoch_sentinels() {
+        let parse = Parse::new(&Utc, None).epoch_sentinels("@@", "@@");
+
+        let test_cases = [
+            ("@@1511648546@@", Utc.ymd(2017, 11, 25).and_hms(22, 22, 26)),
+            (
+                "@@1620021848429@@",
+                Utc.ymd(2021, 5, 3).and_hms_milli(6, 4, 8, 429),
+            ),
+            ("1511648546", Utc.ymd(2017, 11, 25).and_hms(22, 22, 26)),
+        ];
+
+        for &(input, want) in test_cases.iter() {
+            assert_eq!(
+                parse.unix_timestamp(input).unwrap().unwrap(),
+                want,
+                "unix_timestamp_with_epoch_sentinels/{}",
+                input
+            )
+        }
+        assert!(parse.unix_timestamp("@@1511648546").is_none());
+        assert!(parse.unix_timestamp("<<1511648546>>").is_none());
+
+        let parse = Parse::new(&Utc, None).epoch_sentinels("<ts:", ">");
+        assert_eq!(
+            parse.parse("<ts:1511648546>").unwrap(),
+            Utc.ymd(2017, 11, 25).and_hms(22, 22, 26)
+        );
+        assert!(Parse::new(&Utc, None).parse("@@1511648546@@").is_err());
+    }
+
+    #[test]
+    fn empty_input() {
+        let parse = Parse::new(&Utc, None);
+
+        for input in ["", "   ", "\t\n"] {
+            let err = parse.parse(input).unwrap_err();
+            assert_eq!(
+                err.downcast_ref::<ParseError>(),
+                Some(&ParseError::EmptyInput),
+                "empty_input/{:?}",
+                input
+            );
+            assert_eq!(err.to_string(), "input is empty.");
+            assert!(!parse.is_date(input), "empty_input/is_date/{:?}", input);
+        }
+    }
+
+    #[test]
+    fn zero_date() {
+        let parse = Parse::new(&Utc, None);
+
+        for input in [
+            "0000-00-00",
+            "0000-00-00 00:00:00",
+            "0000-00-00 00:00:00.000000",
+        ] {
+            let err = parse.zero_date(input).unwrap().unwrap_err();
+            assert_eq!(
+                err.downcast_ref::<ParseError>(),
+                Some(&ParseError::ZeroDate),
+                "zero_date/{}",
+                input
+            );
+            assert!(parse.parse(input).is_err(), "zero_date/parse/{}", input);
+            assert_eq!(
+                parse.parse_opt(input).unwrap(),
+                None,
+                "zero_date/parse_opt/{}",
+                input
+            );
+        }
+        assert!(parse.zero_date("0000-00-00 00:00:01").is_none());
+        assert!(parse.zero_date("not-date-time").is_none());
+        assert_eq!(
+            parse.parse_opt("2021-04-30 21:14:10").unwrap(),
+            Some(Utc.ymd(2021, 4, 30).and_hms(21, 14, 10))
+        );
+        assert!(parse.parse_opt("not-date-time").is_err());
+    }
+
+    #[test]
+    fn rfc3339() {
+        let parse = Parse::new(&Utc, None);
+
+        let test_cases = [
+            (
+                "2021-05-01T01:17:02.604456Z",
                 Utc.ymd(2021, 5, 1).and_hms_nano(1, 17, 2, 604456000),
             ),
             (
                 "2017-11-25T22:34:50Z",
                 Utc.ymd(2017, 11, 25).and_hms(22, 34, 50),
             ),
+            (
+                "2021-05-01t01:17:02z",
+                Utc.ymd(2021, 5, 1).and_hms(1, 17, 2),
+            ),
+            (
+                "2021-05-01t01:17:02.604456z",
+                Utc.ymd(2021, 5, 1).and_hms_nano(1, 17, 2, 604456000),
+            ),
+            (
+                "2021-05-01T01:17:02z",
+                Utc.ymd(2021, 5, 1).and_hms(1, 17, 2),
+            ),
+            (
+                "2021-05-01t01:17:02Z",
+                Utc.ymd(2021, 5, 1).and_hms(1, 17, 2),
+            ),
+            (
+                "2021-05-01t01:17:02+02:00",
+                Utc.ymd(2021, 4, 30).and_hms(23, 17, 2),
+            ),
         ];
 
         for &(input, want) in test_cases.iter() {
@@ -857,6 +3264,7 @@ mod tests {
             )
         }
         assert!(parse.rfc3339("2017-11-25 22:34:50").is_none());
+        assert!(parse.rfc3339("2017-11-25x22:34:50z").is_none());
         assert!(parse.rfc3339("not-date-time").is_none());
     }
 
@@ -873,6 +3281,14 @@ mod tests {
                 "Wed, 02 Jun 2021 06:31:39 PDT",
                 Utc.ymd(2021, 6, 2).and_hms(13, 31, 39),
             ),
+            (
+                "Wed, 02 Jun 2021 06:31:39 -0000",
+                Utc.ymd(2021, 6, 2).and_hms(6, 31, 39),
+            ),
+            (
+                "Wed, 02 Jun 2021 06:31:39 +0000",
+                Utc.ymd(2021, 6, 2).and_hms(6, 31, 39),
+            ),
         ];
 
         for &(input, want) in test_cases.iter() {
@@ -887,6 +3303,221 @@ mod tests {
         assert!(parse.rfc2822("not-date-time").is_none());
     }
 
+    #[test]
+    fn cookie_date() {
+        let parse = Parse::new(&Utc, None);
+
+        let test_cases = [
+            (
+                "Wed, 09-Jun-2021 10:18:14 GMT",
+                Utc.ymd(2021, 6, 9).and_hms(10, 18, 14),
+            ),
+            (
+                "Wed, 09-Jun-21 10:18:14 GMT",
+                Utc.ymd(2021, 6, 9).and_hms(10, 18, 14),
+            ),
+            (
+                "Thu, 01-Jan-1970 00:00:01 GMT",
+                Utc.ymd(1970, 1, 1).and_hms(0, 0, 1),
+            ),
+            (
+                "Mon, 02-Jan-2006 15:04:05 PST",
+                Utc.ymd(2006, 1, 2).and_hms(23, 4, 5),
+            ),
+        ];
+
+        for &(input, want) in test_cases.iter() {
+            assert_eq!(
+                parse.cookie_date(input).unwrap().unwrap(),
+                want,
+                "cookie_date/{}",
+                input
+            )
+        }
+        assert!(parse.cookie_date("Wed, 09 Jun 2021 10:18:14 GMT").is_none());
+        assert!(parse.cookie_date("not-date-time").is_none());
+    }
+
+    #[test]
+    fn rfc850() {
+        let parse = Parse::new(&Utc, None);
+
+        let test_cases = [
+            (
+                "Monday, 02-Jan-06 15:04:05 GMT",
+                Utc.ymd(2006, 1, 2).and_hms(15, 4, 5),
+            ),
+            (
+                "Wednesday, 02-Jun-21 06:31:39 PDT",
+                Utc.ymd(2021, 6, 2).and_hms(13, 31, 39),
+            ),
+            (
+                "Sunday, 06-Nov-94 08:49:37 GMT",
+                Utc.ymd(1994, 11, 6).and_hms(8, 49, 37),
+            ),
+        ];
+
+        for &(input, want) in test_cases.iter() {
+            assert_eq!(
+                parse.rfc850(input).unwrap().unwrap(),
+                want,
+                "rfc850/{}",
+                input
+            )
+        }
+        assert!(parse.rfc850("Mon, 02-Jan-06 15:04:05 GMT").is_none());
+        assert!(parse.rfc850("not-date-time").is_none());
+    }
+
+    #[test]
+    fn ansi_c() {
+        let parse = Parse::new(&Utc, None);
+
+        let test_cases = [
+            (
+                "Wed Jun  2 06:31:39 2021",
+                Utc.ymd(2021, 6, 2).and_hms(6, 31, 39),
+            ),
+            (
+                "Wed Jun 2 06:31:39 2021",
+                Utc.ymd(2021, 6, 2).and_hms(6, 31, 39),
+            ),
+            (
+                "Sat Jun 12 06:31:39 2021",
+                Utc.ymd(2021, 6, 12).and_hms(6, 31, 39),
+            ),
+        ];
+
+        for &(input, want) in test_cases.iter() {
+            assert_eq!(
+                parse.ansi_c(input).unwrap().unwrap(),
+                want,
+                "ansi_c/{}",
+                input
+            )
+        }
+        assert!(parse.ansi_c("Wed Jun  2 06:31:39 UTC 2021").is_none());
+        assert!(parse.ansi_c("not-date-time").is_none());
+    }
+
+    #[test]
+    fn js_date_string() {
+        let parse = Parse::new(&Utc, None);
+
+        let test_cases = [
+            (
+                "Wed Jun 02 2021 06:31:39 GMT-0700",
+                Utc.ymd(2021, 6, 2).and_hms(13, 31, 39),
+            ),
+            (
+                "Thu Jul 01 2021 09:00:00 GMT+0200",
+                Utc.ymd(2021, 7, 1).and_hms(7, 0, 0),
+            ),
+            (
+                "Mon Nov 01 2021 23:15:00 GMT+0530",
+                Utc.ymd(2021, 11, 1).and_hms(17, 45, 0),
+            ),
+            (
+                "Jun 02 2021 06:31:39 GMT-0700",
+                Utc.ymd(2021, 6, 2).and_hms(13, 31, 39),
+            ),
+        ];
+
+        for &(input, want) in test_cases.iter() {
+            assert_eq!(
+                parse.js_date_string(input).unwrap().unwrap(),
+                want,
+                "js_date_string/{}",
+                input
+            )
+        }
+        assert!(parse
+            .js_date_string("Wed Jun 02 2021 06:31:39 GMT+2500")
+            .unwrap()
+            .is_err());
+        assert!(parse
+            .js_date_string("Wed Jun  2 06:31:39 UTC 2021")
+            .is_none());
+        assert!(parse.js_date_string("not-date-time").is_none());
+    }
+
+    #[test]
+    fn unix_date() {
+        let parse = Parse::new(&Utc, None);
+
+        let test_cases = [
+            (
+                "Wed Jun  2 06:31:39 UTC 2021",
+                Utc.ymd(2021, 6, 2).and_hms(6, 31, 39),
+            ),
+            (
+                "Wed Jun  2 06:31:39 PDT 2021",
+                Utc.ymd(2021, 6, 2).and_hms(13, 31, 39),
+            ),
+            (
+                "Sat Jun 12 06:31:39 +0800 2021",
+                Utc.ymd(2021, 6, 11).and_hms(22, 31, 39),
+            ),
+            // the weekday is not checked against the date
+            (
+                "Mon Jun  2 06:31:39 GMT 2021",
+                Utc.ymd(2021, 6, 2).and_hms(6, 31, 39),
+            ),
+        ];
+
+        for &(input, want) in test_cases.iter() {
+            assert_eq!(
+                parse.unix_date(input).unwrap().unwrap(),
+                want,
+                "unix_date/{}",
+                input
+            )
+        }
+        assert!(parse.unix_date("Wed Jun  2 06:31:39 2021").is_none());
+        assert!(parse.unix_date("not-date-time").is_none());
+    }
+
+    #[test]
+    fn go_time_string() {
+        let parse = Parse::new(&Utc, None);
+
+        let test_cases = [
+            (
+                "2021-05-01 10:00:00.123456789 +0000 UTC",
+                Utc.ymd(2021, 5, 1).and_hms_nano(10, 0, 0, 123456789),
+            ),
+            (
+                "2021-05-01 18:00:00 +0800 CST",
+                Utc.ymd(2021, 5, 1).and_hms(10, 0, 0),
+            ),
+            (
+                "2021-05-01 03:00:00.5 -0700 PDT",
+                Utc.ymd(2021, 5, 1).and_hms_milli(10, 0, 0, 500),
+            ),
+            (
+                "2021-05-01 10:00:00.123456789 +0000 UTC m=+0.000012345",
+                Utc.ymd(2021, 5, 1).and_hms_nano(10, 0, 0, 123456789),
+            ),
+        ];
+
+        for &(input, want) in test_cases.iter() {
+            assert_eq!(
+                parse.go_time_string(input).unwrap().unwrap(),
+                want,
+                "go_time_string/{}",
+                input
+            )
+        }
+        assert_eq!(
+            parse
+                .parse("2021-05-01 10:00:00.123456789 +0000 UTC")
+                .unwrap(),
+            Utc.ymd(2021, 5, 1).and_hms_nano(10, 0, 0, 123456789)
+        );
+        assert!(parse.go_time_string("2021-05-01 10:00:00 UTC").is_none());
+        assert!(parse.go_time_string("not-date-time").is_none());
+    }
+
     #[test]
     fn postgres_timestamp() {
         let parse = Parse::new(&Utc, None);
@@ -916,6 +3547,14 @@ mod tests {
                 "2017-07-19 03:21:51+00:00",
                 Utc.ymd(2017, 7, 19).and_hms(3, 21, 51),
             ),
+            (
+                "2017-07-19 03:21:51+00:00:30",
+                Utc.ymd(2017, 7, 19).and_hms(3, 21, 51),
+            ),
+            (
+                "2017-07-19 03:21:51+05:30:15",
+                Utc.ymd(2017, 7, 18).and_hms(21, 51, 51),
+            ),
         ];
 
         for &(input, want) in test_cases.iter() {
@@ -933,7 +3572,7 @@ mod tests {
     fn ymd_hms() {
         let parse = Parse::new(&Utc, None);
 
-        let test_cases = vec![
+        let test_cases = [
             ("2021-04-30 21:14", Utc.ymd(2021, 4, 30).and_hms(21, 14, 0)),
             (
                 "2021-04-30 21:14:10",
@@ -976,7 +3615,7 @@ mod tests {
     fn ymd_hms_z() {
         let parse = Parse::new(&Utc, None);
 
-        let test_cases = vec![
+        let test_cases = [
             (
                 "2017-11-25 13:31:15 PST",
                 Utc.ymd(2017, 11, 25).and_hms(21, 31, 15),
@@ -993,6 +3632,30 @@ mod tests {
                 "2014-12-16 06:20:00 GMT",
                 Utc.ymd(2014, 12, 16).and_hms(6, 20, 0),
             ),
+            (
+                "2014-12-16 06:20:00 Zulu",
+                Utc.ymd(2014, 12, 16).and_hms(6, 20, 0),
+            ),
+            (
+                "2014-04-26 05:24:37 PM PST",
+                Utc.ymd(2014, 4, 27).and_hms(1, 24, 37),
+            ),
+            (
+                "2014-04-26 05:24 am UTC",
+                Utc.ymd(2014, 4, 26).and_hms(5, 24, 0),
+            ),
+            (
+                "2014-04-26 12:24:37 AM +0800",
+                Utc.ymd(2014, 4, 25).and_hms(16, 24, 37),
+            ),
+            (
+                "2014-12-16 06:20:00 IST",
+                Utc.ymd(2014, 12, 16).and_hms(0, 50, 0),
+            ),
+            (
+                "2014-12-16 06:20:00 NPT",
+                Utc.ymd(2014, 12, 16).and_hms(0, 35, 0),
+            ),
             (
                 "2014-04-26 13:13:43 +0800",
                 Utc.ymd(2014, 4, 26).and_hms(5, 13, 43),
@@ -1009,6 +3672,26 @@ mod tests {
                 "2015-09-30 18:48:56.35272715 UTC",
                 Utc.ymd(2015, 9, 30).and_hms_nano(18, 48, 56, 352727150),
             ),
+            (
+                "2012-08-03 18:31:59 +0000UTC",
+                Utc.ymd(2012, 8, 3).and_hms(18, 31, 59),
+            ),
+            (
+                "2012-08-03 11:31:59 -0700PDT",
+                Utc.ymd(2012, 8, 3).and_hms(18, 31, 59),
+            ),
+            (
+                "2012-08-03 18:31:59.257 +05:30IST",
+                Utc.ymd(2012, 8, 3).and_hms_milli(13, 1, 59, 257),
+            ),
+            (
+                "2021-05-01 10:00:00 GMT+8",
+                Utc.ymd(2021, 5, 1).and_hms(2, 0, 0),
+            ),
+            (
+                "2021-05-01 10:00:00 UTC-05:00",
+                Utc.ymd(2021, 5, 1).and_hms(15, 0, 0),
+            ),
         ];
 
         for &(input, want) in test_cases.iter() {
@@ -1020,21 +3703,151 @@ mod tests {
             )
         }
         assert!(parse.ymd_hms_z("not-date-time").is_none());
+        assert!(Parse::new(&Utc, None)
+            .allow_12_hour(false)
+            .ymd_hms_z("2014-04-26 05:24:37 PM PST")
+            .is_none()); // anything glued to a zone name is not ignored
+        for input in [
+            "2021-05-01 10:00:00 PST123",
+            "2021-05-01 10:00:00 PST+8",
+            "2021-05-01T10:00:00Z,extra",
+        ] {
+            assert!(parse.parse(input).is_err(), "ymd_hms_z/{}", input);
+        }
+        assert!(parse.parse_prefix("2021-05-01T10:00:00Z,extra").is_err());
+        assert!(parse
+            .parse_first_in_text("2021-05-01T10:00:00Z,extra")
+            .is_err());
     }
 
     #[test]
-    fn ymd() {
-        let parse = Parse::new(&Utc, Some(Utc::now().time()));
+    fn ymd_with_t_separator() {
+        let parse = Parse::new(&Utc, None);
 
-        let test_cases = [(
-            "2021-02-21",
+        let test_cases = [
+            (
+                "2021-05-14T18:51:00 GMT",
+                Utc.ymd(2021, 5, 14).and_hms(18, 51, 0),
+            ),
+            (
+                "2021-05-14T18:51 PST",
+                Utc.ymd(2021, 5, 15).and_hms(2, 51, 0),
+            ),
+            (
+                "2021-05-14T18:51:00.123 UTC",
+                Utc.ymd(2021, 5, 14).and_hms_milli(18, 51, 0, 123),
+            ),
+        ];
+
+        for &(input, want) in test_cases.iter() {
+            assert_eq!(
+                parse.parse(input).unwrap(),
+                want,
+                "ymd_with_t_separator/{}",
+                input
+            )
+        }
+        assert!(parse.parse("2021-05-14T18:51:00 not-a-timezone").is_err());
+    }
+
+    #[test]
+    fn zone_casing() {
+        let parse = Parse::new(&Utc, Some(NaiveTime::from_hms(0, 0, 0)));
+        let now = Utc::now();
+
+        for (input, want) in [
+            (
+                "2017-11-25 13:31:15 Utc",
+                Utc.ymd(2017, 11, 25).and_hms(13, 31, 15),
+            ),
+            (
+                "2017-11-25 13:31:15 gmt",
+                Utc.ymd(2017, 11, 25).and_hms(13, 31, 15),
+            ),
+            (
+                "2017-11-25 13:31:15 Pdt",
+                Utc.ymd(2017, 11, 25).and_hms(20, 31, 15),
+            ),
+            (
+                "2017-11-25 13:31 pSt",
+                Utc.ymd(2017, 11, 25).and_hms(21, 31, 0),
+            ),
+            (
+                "2017-11-25 13:31:15 gMt+8",
+                Utc.ymd(2017, 11, 25).and_hms(5, 31, 15),
+            ),
+        ] {
+            assert_eq!(
+                parse.ymd_hms_z(input).unwrap().unwrap(),
+                want,
+                "ymd_hms_z/{}",
+                input
+            );
+        }
+
+        for (input, want) in [
+            ("2021-02-21 Pdt", Utc.ymd(2021, 2, 21).and_hms(7, 0, 0)),
+            ("2021-02-21 utc", Utc.ymd(2021, 2, 21).and_hms(0, 0, 0)),
+            ("2021-02-21 eSt", Utc.ymd(2021, 2, 21).and_hms(5, 0, 0)),
+        ] {
+            assert_eq!(
+                parse.ymd_z(input, &now).unwrap().unwrap(),
+                want,
+                "ymd_z/{}",
+                input
+            );
+        }
+
+        for (input, utc_hms) in [
+            ("01:06:06 Pdt", (8, 6, 6)),
+            ("4:00pm gMt", (16, 0, 0)),
+            ("6:00 AM uTC", (6, 0, 0)),
+        ] {
+            let (h, m, s) = utc_hms;
+            assert_eq!(
+                parse.hms_z(input, &now).unwrap().unwrap().time(),
+                NaiveTime::from_hms(h, m, s),
+                "hms_z/{}",
+                input
+            );
+        }
+
+        for (input, want) in [
+            (
+                "May 02, 2021 15:51 Utc",
+                Utc.ymd(2021, 5, 2).and_hms(15, 51, 0),
+            ),
+            (
+                "May 26, 2021, 12:49 AM pDT",
+                Utc.ymd(2021, 5, 26).and_hms(7, 49, 0),
+            ),
+            (
+                "September 17, 2012 10:09am gmT",
+                Utc.ymd(2012, 9, 17).and_hms(10, 9, 0),
+            ),
+        ] {
+            assert_eq!(
+                parse.month_mdy_hms_z(input).unwrap().unwrap(),
+                want,
+                "month_mdy_hms_z/{}",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn ymd() {
+        let parse = Parse::new(&Utc, Some(Utc::now().time()));
+
+        let test_cases = [(
+            "2021-02-21",
             Utc.ymd(2021, 2, 21).and_time(Utc::now().time()),
         )];
 
         for &(input, want) in test_cases.iter() {
             assert_eq!(
                 parse
-                    .ymd(input)
+                    .ymd(input, &Utc::now())
                     .unwrap()
                     .unwrap()
                     .trunc_subsecs(0)
@@ -1045,7 +3858,7 @@ mod tests {
                 input
             )
         }
-        assert!(parse.ymd("not-date-time").is_none());
+        assert!(parse.ymd("not-date-time", &Utc::now()).is_none());
     }
 
     #[test]
@@ -1076,12 +3889,19 @@ mod tests {
                     .and_time(now_at_cst.time())
                     .map(|dt| dt.with_timezone(&Utc)),
             ),
+            (
+                "2020-07-20 +08",
+                FixedOffset::east(8 * 3600)
+                    .ymd(2020, 7, 20)
+                    .and_time(now_at_cst.time())
+                    .map(|dt| dt.with_timezone(&Utc)),
+            ),
         ];
 
         for &(input, want) in test_cases.iter() {
             assert_eq!(
                 parse
-                    .ymd_z(input)
+                    .ymd_z(input, &Utc::now())
                     .unwrap()
                     .unwrap()
                     .trunc_subsecs(0)
@@ -1092,7 +3912,295 @@ mod tests {
                 input
             )
         }
-        assert!(parse.ymd_z("not-date-time").is_none());
+        assert!(parse.ymd_z("not-date-time", &Utc::now()).is_none());
+    }
+
+    #[test]
+    fn negative_ymd() {
+        let parse = Parse::new(&Utc, Some(NaiveTime::from_hms(0, 0, 0))).negative_years(true);
+
+        let test_cases = [
+            ("-0044-03-15", Utc.ymd(-44, 3, 15).and_hms(0, 0, 0)),
+            ("-0001-12-31", Utc.ymd(-1, 12, 31).and_hms(0, 0, 0)),
+            ("-12000-01-01", Utc.ymd(-12000, 1, 1).and_hms(0, 0, 0)),
+            ("-0044-03-15 12:30", Utc.ymd(-44, 3, 15).and_hms(12, 30, 0)),
+            (
+                "-0044-03-15T12:30:45",
+                Utc.ymd(-44, 3, 15).and_hms(12, 30, 45),
+            ),
+        ];
+
+        for &(input, want) in test_cases.iter() {
+            assert_eq!(
+                parse.negative_ymd(input, &Utc::now()).unwrap().unwrap(),
+                want,
+                "negative_ymd/{}",
+                input
+            );
+            assert_eq!(
+                parse.parse(input).unwrap(),
+                want,
+                "negative_ymd/parse/{}",
+                input
+            );
+        }
+        let err = parse.parse("-0044-02-30").unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<ParseError>(),
+            Some(&ParseError::InvalidDate("-0044-02-30".to_string()))
+        );
+        assert!(parse.negative_ymd("0044-03-15", &Utc::now()).is_none());
+        assert!(Parse::new(&Utc, None).parse("-0044-03-15").is_err());
+    }
+
+    #[test]
+    fn parse_from_threads() {
+        let parse = Parse::new(&Utc, Some(NaiveTime::from_hms(0, 0, 0)));
+        let inputs = [
+            "1511648546",
+            "Wed, 02 Jun 2021 06:31:39 GMT",
+            "2017-11-25T22:34:50Z",
+            "2021-04-30 21:14:10",
+            "2017-11-25 13:31:15 PST",
+            "2021-02-21",
+            "May 8, 2009 5:57:51 PM",
+            "14 May 2019 19:11:40.164",
+            "03/19/2012 10:11:59",
+            "2014/3/31",
+            "171113 14:14:20",
+            "2014年04月08日11时25分18秒",
+            "not a datetime string",
+        ];
+
+        // all threads wait on the barrier and then start parsing at once, so that unless another
+        // test got there first, their first parses race to compile the same regexes
+        let barrier = std::sync::Barrier::new(8);
+        let parsed: Vec<Vec<Option<DateTime<Utc>>>> = std::thread::scope(|scope| {
+            let handles: Vec<_> = (0..8)
+                .map(|_| {
+                    scope.spawn(|| {
+                        barrier.wait();
+                        inputs.iter().map(|input| parse.try_parse(input)).collect()
+                    })
+                })
+                .collect();
+            handles.into_iter().map(|h| h.join().unwrap()).collect()
+        });
+
+        let want: Vec<Option<DateTime<Utc>>> = inputs.iter().map(|i| parse.try_parse(i)).collect();
+        assert!(want[..want.len() - 1].iter().all(Option::is_some));
+        for results in parsed.iter() {
+            for (index, result) in results.iter().enumerate() {
+                assert_eq!(*result, want[index], "parse_from_threads/{}", inputs[index]);
+            }
+        }
+    }
+
+    #[test]
+    fn try_parse_and_is_date() {
+        let parse = Parse::new(&Utc, None);
+
+        // a date
+        let input = "2021-05-01 10:00:00 +0800";
+        assert!(parse.is_date(input));
+        assert_eq!(
+            parse.try_parse(input),
+            Some(Utc.ymd(2021, 5, 1).and_hms(2, 0, 0))
+        );
+
+        // a date with a bad offset is a date that fails to parse
+        let input = "2021-05-01 10:00:00 +2500";
+        assert!(parse.is_date(input));
+        assert_eq!(parse.try_parse(input), None);
+        assert_eq!(
+            parse.parse(input).unwrap_err().to_string(),
+            "input is out of range"
+        );
+
+        // not a date
+        for input in ["not a date", "2021-05-01 10:00 in the morning"] {
+            assert!(!parse.is_date(input), "is_date/{}", input);
+            assert_eq!(parse.try_parse(input), None, "try_parse/{}", input);
+            assert_eq!(
+                parse.parse(input).unwrap_err().to_string(),
+                format!("{} did not match any formats.", input)
+            );
+        }
+    }
+
+    #[test]
+    fn date_only_policy() {
+        let start_of_day = Parse::new(&Utc, None).date_only_policy(DateOnlyPolicy::StartOfDay);
+
+        let test_cases = [
+            ("2021-02-21", Utc.ymd(2021, 2, 21).and_hms(0, 0, 0)),
+            ("2021-02-21 PST", Utc.ymd(2021, 2, 21).and_hms(8, 0, 0)),
+            ("21-02-21", Utc.ymd(2021, 2, 21).and_hms(0, 0, 0)),
+            ("May 25, 2021", Utc.ymd(2021, 5, 25).and_hms(0, 0, 0)),
+            ("1 July 2013", Utc.ymd(2013, 7, 1).and_hms(0, 0, 0)),
+            ("08/21/71", Utc.ymd(1971, 8, 21).and_hms(0, 0, 0)),
+            ("2014/3/31", Utc.ymd(2014, 3, 31).and_hms(0, 0, 0)),
+            ("2014.03.30", Utc.ymd(2014, 3, 30).and_hms(0, 0, 0)),
+            ("2014年04月08日", Utc.ymd(2014, 4, 8).and_hms(0, 0, 0)),
+            ("Week 23 of 2021", Utc.ymd(2021, 6, 7).and_hms(0, 0, 0)),
+        ];
+
+        for &(input, want) in test_cases.iter() {
+            assert_eq!(
+                start_of_day.parse(input).unwrap(),
+                want,
+                "date_only_policy/{}",
+                input
+            )
+        }
+
+        // a default time wins over the policy
+        let default_time = Parse::new(&Utc, Some(NaiveTime::from_hms(12, 30, 0)))
+            .date_only_policy(DateOnlyPolicy::StartOfDay);
+        assert_eq!(
+            default_time.parse("2021-02-21").unwrap(),
+            Utc.ymd(2021, 2, 21).and_hms(12, 30, 0)
+        );
+
+        // the current time is used by default
+        let now = Utc::now();
+        let parsed = Parse::new(&Utc, None).ymd("2021-02-21", &now).unwrap();
+        assert_eq!(
+            parsed.unwrap(),
+            Utc.ymd(2021, 2, 21).and_time(now.time()).unwrap()
+        );
+    }
+
+    #[test]
+    fn yy_mm_dd() {
+        let midnight = Some(NaiveTime::from_hms(0, 0, 0));
+        let ymd = Parse::new(&Utc, midnight);
+        let ydm = Parse::new(&Utc, midnight).date_order(DateOrder::Ydm);
+
+        let test_cases = [
+            (
+                "21-02-11",
+                Utc.ymd(2021, 2, 11).and_hms(0, 0, 0),
+                Utc.ymd(2021, 11, 2).and_hms(0, 0, 0),
+            ),
+            (
+                "14-08-04",
+                Utc.ymd(2014, 8, 4).and_hms(0, 0, 0),
+                Utc.ymd(2014, 4, 8).and_hms(0, 0, 0),
+            ),
+            (
+                "99-12-01",
+                Utc.ymd(1999, 12, 1).and_hms(0, 0, 0),
+                Utc.ymd(1999, 1, 12).and_hms(0, 0, 0),
+            ),
+        ];
+
+        for &(input, want_ymd, want_ydm) in test_cases.iter() {
+            assert_eq!(
+                ymd.yy_mm_dd(input, &Utc::now()).unwrap().unwrap(),
+                want_ymd,
+                "yy_mm_dd/ymd/{}",
+                input
+            );
+            assert_eq!(
+                ydm.yy_mm_dd(input, &Utc::now()).unwrap().unwrap(),
+                want_ydm,
+                "yy_mm_dd/ydm/{}",
+                input
+            );
+        }
+        assert!(ymd.yy_mm_dd("21-13-01", &Utc::now()).is_none());
+        assert!(ymd.yy_mm_dd("2021-02-21", &Utc::now()).is_none());
+        assert!(ymd.parse("21-02-21").is_ok());
+        assert!(ydm.parse("21-02-21").is_err());
+    }
+
+    #[test]
+    fn basic_iso_datetime() {
+        let parse = Parse::new(&Utc, None);
+
+        assert_eq!(
+            parse
+                .basic_iso_datetime("20210501T101112")
+                .unwrap()
+                .unwrap(),
+            Utc.ymd(2021, 5, 1).and_hms(10, 11, 12)
+        );
+        assert_eq!(
+            parse.parse("20210501T101112").unwrap(),
+            Utc.ymd(2021, 5, 1).and_hms(10, 11, 12)
+        );
+        let test_cases = [
+            ("20210501T101112Z", Utc.ymd(2021, 5, 1).and_hms(10, 11, 12)),
+            (
+                "20210501T101112+0800",
+                Utc.ymd(2021, 5, 1).and_hms(2, 11, 12),
+            ),
+            (
+                "20210501T101112-0530",
+                Utc.ymd(2021, 5, 1).and_hms(15, 41, 12),
+            ),
+            ("20210501T101112+08", Utc.ymd(2021, 5, 1).and_hms(2, 11, 12)),
+        ];
+        for &(input, want) in test_cases.iter() {
+            assert_eq!(
+                parse.parse(input).unwrap(),
+                want,
+                "basic_iso_datetime/{}",
+                input
+            );
+        }
+        // the offset is read on its own, not in the parsing timezone
+        let east = FixedOffset::east(9 * 3600);
+        assert_eq!(
+            Parse::new(&east, None).parse("20210501T101112Z").unwrap(),
+            Utc.ymd(2021, 5, 1).and_hms(10, 11, 12)
+        );
+        assert!(parse
+            .basic_iso_datetime("20210501T101112+2500")
+            .unwrap()
+            .is_err());
+        assert!(parse.basic_iso_datetime("20210501T101112+08:00").is_none());
+        assert!(parse.basic_iso_datetime("20210230T101112").is_none());
+        assert!(parse.basic_iso_datetime("20210501T251112").is_none());
+        assert!(parse.basic_iso_datetime("20210501 101112").is_none());
+        assert!(parse.basic_iso_datetime("not-date-time").is_none());
+    }
+
+    #[test]
+    fn basic_iso_date() {
+        let parse = Parse::new(&Utc, Some(NaiveTime::from_hms(0, 0, 0)));
+
+        let test_cases = [
+            ("20210501", Utc.ymd(2021, 5, 1).and_hms(0, 0, 0)),
+            ("19991231", Utc.ymd(1999, 12, 31).and_hms(0, 0, 0)),
+        ];
+
+        for &(input, want) in test_cases.iter() {
+            assert_eq!(
+                parse.basic_iso_date(input, &Utc::now()).unwrap().unwrap(),
+                want,
+                "basic_iso_date/{}",
+                input
+            );
+            assert_eq!(
+                parse.parse(input).unwrap(),
+                want,
+                "basic_iso_date/parse/{}",
+                input
+            );
+        }
+        assert!(parse.basic_iso_date("20210230", &Utc::now()).is_none());
+        assert!(parse.basic_iso_date("2021050", &Utc::now()).is_none());
+        assert!(parse.basic_iso_date("202105011", &Utc::now()).is_none());
+        assert!(parse.basic_iso_date("not-date", &Utc::now()).is_none());
+        // a bare year is still not a date
+        assert!(parse.parse("2021").is_err());
+        // ten digits are still a unix timestamp
+        assert_eq!(
+            parse.parse("2021050112").unwrap(),
+            Utc.timestamp(2021050112, 0)
+        );
     }
 
     #[test]
@@ -1112,17 +4220,37 @@ mod tests {
                 "6:00 AM",
                 Utc::now().date().and_time(NaiveTime::from_hms(6, 0, 0)),
             ),
+            (
+                "3:04PM",
+                Utc::now().date().and_time(NaiveTime::from_hms(15, 4, 0)),
+            ),
+            (
+                "3:04pm",
+                Utc::now().date().and_time(NaiveTime::from_hms(15, 4, 0)),
+            ),
+            (
+                "3:04Pm",
+                Utc::now().date().and_time(NaiveTime::from_hms(15, 4, 0)),
+            ),
+            (
+                "12:00AM",
+                Utc::now().date().and_time(NaiveTime::from_hms(0, 0, 0)),
+            ),
+            (
+                "12:00PM",
+                Utc::now().date().and_time(NaiveTime::from_hms(12, 0, 0)),
+            ),
         ];
 
         for &(input, want) in test_cases.iter() {
             assert_eq!(
-                parse.hms(input).unwrap().unwrap(),
+                parse.hms(input, &Utc::now()).unwrap().unwrap(),
                 want.unwrap(),
                 "hms/{}",
                 input
             )
         }
-        assert!(parse.hms("not-date-time").is_none());
+        assert!(parse.hms("not-date-time", &Utc::now()).is_none());
     }
 
     #[test]
@@ -1163,13 +4291,116 @@ mod tests {
 
         for &(input, want) in test_cases.iter() {
             assert_eq!(
-                parse.hms_z(input).unwrap().unwrap(),
+                parse.hms_z(input, &Utc::now()).unwrap().unwrap(),
                 want.unwrap(),
                 "hms_z/{}",
                 input
             )
         }
-        assert!(parse.hms_z("not-date-time").is_none());
+        assert!(parse.hms_z("not-date-time", &Utc::now()).is_none());
+    }
+
+    #[test]
+    fn hour_meridiem() {
+        let parse = Parse::new(&Utc, None);
+
+        let test_cases = [
+            (
+                "11AM",
+                Utc::now().date().and_time(NaiveTime::from_hms(11, 0, 0)),
+            ),
+            (
+                "3PM",
+                Utc::now().date().and_time(NaiveTime::from_hms(15, 0, 0)),
+            ),
+            (
+                "3pm",
+                Utc::now().date().and_time(NaiveTime::from_hms(15, 0, 0)),
+            ),
+            (
+                "12AM",
+                Utc::now().date().and_time(NaiveTime::from_hms(0, 0, 0)),
+            ),
+            (
+                "12PM",
+                Utc::now().date().and_time(NaiveTime::from_hms(12, 0, 0)),
+            ),
+            (
+                "4pm",
+                Utc::now().date().and_time(NaiveTime::from_hms(16, 0, 0)),
+            ),
+            (
+                "7 AM",
+                Utc::now().date().and_time(NaiveTime::from_hms(7, 0, 0)),
+            ),
+            (
+                "7 Pm",
+                Utc::now().date().and_time(NaiveTime::from_hms(19, 0, 0)),
+            ),
+            (
+                "12am",
+                Utc::now().date().and_time(NaiveTime::from_hms(0, 0, 0)),
+            ),
+            (
+                "12 pm",
+                Utc::now().date().and_time(NaiveTime::from_hms(12, 0, 0)),
+            ),
+        ];
+
+        for &(input, want) in test_cases.iter() {
+            assert_eq!(
+                parse.hour_meridiem(input, &Utc::now()).unwrap().unwrap(),
+                want.unwrap(),
+                "hour_meridiem/{}",
+                input
+            )
+        }
+        assert!(parse.hour_meridiem("11", &Utc::now()).is_none());
+        assert!(parse.hour_meridiem("13PM", &Utc::now()).is_none());
+        assert!(parse.hour_meridiem("0 am", &Utc::now()).is_none());
+        assert!(parse.hour_meridiem("7  AMT", &Utc::now()).is_none());
+        assert!(parse.hour_meridiem("not-date-time", &Utc::now()).is_none());
+    }
+
+    #[test]
+    fn compact_hm() {
+        let parse = Parse::new(&Utc, None).compact_time(true);
+
+        let test_cases = [
+            (
+                "1430",
+                Utc::now().date().and_time(NaiveTime::from_hms(14, 30, 0)),
+            ),
+            (
+                "0930",
+                Utc::now().date().and_time(NaiveTime::from_hms(9, 30, 0)),
+            ),
+            (
+                "0000",
+                Utc::now().date().and_time(NaiveTime::from_hms(0, 0, 0)),
+            ),
+            (
+                "2359",
+                Utc::now().date().and_time(NaiveTime::from_hms(23, 59, 0)),
+            ),
+        ];
+
+        for &(input, want) in test_cases.iter() {
+            assert_eq!(
+                parse.compact_hm(input, &Utc::now()).unwrap().unwrap(),
+                want.unwrap(),
+                "compact_hm/{}",
+                input
+            )
+        }
+        assert!(parse.compact_hm("2560", &Utc::now()).is_none());
+        assert!(parse.compact_hm("1460", &Utc::now()).is_none());
+        assert!(parse.compact_hm("930", &Utc::now()).is_none());
+        assert!(parse.compact_hm("not-date-time", &Utc::now()).is_none());
+
+        let parse = Parse::new(&Utc, None);
+        assert!(parse.compact_hm("1430", &Utc::now()).is_none());
+        assert!(parse.parse("1430").is_err());
     }
 
     #[test]
@@ -1184,7 +4415,7 @@ mod tests {
         for &(input, want) in test_cases.iter() {
             assert_eq!(
                 parse
-                    .month_ymd(input)
+                    .month_ymd(input, &Utc::now())
                     .unwrap()
                     .unwrap()
                     .trunc_subsecs(0)
@@ -1195,41 +4426,358 @@ mod tests {
                 input
             )
         }
-        assert!(parse.month_ymd("not-date-time").is_none());
+        assert!(parse.month_ymd("not-date-time", &Utc::now()).is_none());
     }
 
     #[test]
-    fn month_md_hms() {
-        let parse = Parse::new(&Utc, None);
+    fn quarter() {
+        let parse = Parse::new(&Utc, Some(NaiveTime::from_hms(0, 0, 0)));
 
         let test_cases = [
-            (
-                "May 6 at 9:24 PM",
-                Utc.ymd(Utc::now().year(), 5, 6).and_hms(21, 24, 0),
-            ),
-            (
-                "May 27 02:45:27",
-                Utc.ymd(Utc::now().year(), 5, 27).and_hms(2, 45, 27),
-            ),
+            ("Q2 2021", Utc.ymd(2021, 4, 1).and_hms(0, 0, 0)),
+            ("2021-Q2", Utc.ymd(2021, 4, 1).and_hms(0, 0, 0)),
+            ("Q1 2021", Utc.ymd(2021, 1, 1).and_hms(0, 0, 0)),
+            ("q3 2021", Utc.ymd(2021, 7, 1).and_hms(0, 0, 0)),
+            ("2021-Q4", Utc.ymd(2021, 10, 1).and_hms(0, 0, 0)),
         ];
 
         for &(input, want) in test_cases.iter() {
             assert_eq!(
-                parse.month_md_hms(input).unwrap().unwrap(),
+                parse.quarter(input, &Utc::now()).unwrap().unwrap(),
                 want,
-                "month_md_hms/{}",
+                "quarter/{}",
                 input
             )
         }
-        assert!(parse.month_md_hms("not-date-time").is_none());
+        assert!(parse.quarter("Q5 2021", &Utc::now()).is_none());
+        assert!(parse.quarter("2021-Q0", &Utc::now()).is_none());
+        assert!(parse.quarter("not-date-time", &Utc::now()).is_none());
     }
 
     #[test]
-    fn month_mdy_hms() {
-        let parse = Parse::new(&Utc, None);
+    fn year_month() {
+        let parse = Parse::new(&Utc, Some(NaiveTime::from_hms(0, 0, 0)));
 
         let test_cases = [
-            (
+            ("2021-05", Utc.ymd(2021, 5, 1).and_hms(0, 0, 0)),
+            ("2021/05", Utc.ymd(2021, 5, 1).and_hms(0, 0, 0)),
+            ("2021/5", Utc.ymd(2021, 5, 1).and_hms(0, 0, 0)),
+            ("May 2021", Utc.ymd(2021, 5, 1).and_hms(0, 0, 0)),
+            ("February 2021", Utc.ymd(2021, 2, 1).and_hms(0, 0, 0)),
+            ("2021-May", Utc.ymd(2021, 5, 1).and_hms(0, 0, 0)),
+        ];
+
+        for &(input, want) in test_cases.iter() {
+            let parsed = parse.year_month(input, &Utc::now()).unwrap().unwrap();
+            assert_eq!(parsed, want, "year_month/{}", input);
+            assert_eq!(parsed.day(), 1, "year_month/{}", input)
+        }
+        assert!(parse.year_month("2021-13", &Utc::now()).is_none());
+        assert!(parse.year_month("not-date-time", &Utc::now()).is_none());
+    }
+
+    #[test]
+    fn era_year() {
+        let parse = Parse::new(&Utc, Some(NaiveTime::from_hms(0, 0, 0))).negative_years(true);
+
+        let test_cases = [
+            ("44 BC", Utc.ymd(-43, 1, 1).and_hms(0, 0, 0)),
+            ("44 BCE", Utc.ymd(-43, 1, 1).and_hms(0, 0, 0)),
+            ("44bc", Utc.ymd(-43, 1, 1).and_hms(0, 0, 0)),
+            ("1 BC", Utc.ymd(0, 1, 1).and_hms(0, 0, 0)),
+            ("753 BC", Utc.ymd(-752, 1, 1).and_hms(0, 0, 0)),
+        ];
+
+        for &(input, want) in test_cases.iter() {
+            assert_eq!(
+                parse.era_year(input, &Utc::now()).unwrap().unwrap(),
+                want,
+                "era_year/{}",
+                input
+            );
+            assert_eq!(
+                parse.parse(input).unwrap(),
+                want,
+                "era_year/parse/{}",
+                input
+            );
+        }
+        assert!(parse.era_year("0 BC", &Utc::now()).is_none());
+        assert!(parse.era_year("44 AD", &Utc::now()).is_none());
+        assert!(Parse::new(&Utc, None).parse("44 BC").is_err());
+    }
+
+    #[test]
+    fn relative_weekday() {
+        let midnight = Some(NaiveTime::from_hms(0, 0, 0));
+        let parse = Parse::new(&Utc, midnight);
+        let sunday_first = Parse::new(&Utc, midnight).week_start(Weekday::Sun);
+        // a Wednesday
+        let now = Utc.ymd(2021, 5, 5).and_hms(10, 0, 0);
+
+        let test_cases = [
+            ("next Friday", Utc.ymd(2021, 5, 7), Utc.ymd(2021, 5, 7)),
+            ("next wednesday", Utc.ymd(2021, 5, 12), Utc.ymd(2021, 5, 12)),
+            ("next mon", Utc.ymd(2021, 5, 10), Utc.ymd(2021, 5, 10)),
+            ("last Friday", Utc.ymd(2021, 4, 30), Utc.ymd(2021, 4, 30)),
+            ("last Wednesday", Utc.ymd(2021, 4, 28), Utc.ymd(2021, 4, 28)),
+            ("LAST TUE", Utc.ymd(2021, 5, 4), Utc.ymd(2021, 5, 4)),
+            ("this Monday", Utc.ymd(2021, 5, 3), Utc.ymd(2021, 5, 3)),
+            ("this Sunday", Utc.ymd(2021, 5, 9), Utc.ymd(2021, 5, 2)),
+            ("this saturday", Utc.ymd(2021, 5, 8), Utc.ymd(2021, 5, 8)),
+            ("this week", Utc.ymd(2021, 5, 3), Utc.ymd(2021, 5, 2)),
+            ("last week", Utc.ymd(2021, 4, 26), Utc.ymd(2021, 4, 25)),
+            ("Next Week", Utc.ymd(2021, 5, 10), Utc.ymd(2021, 5, 9)),
+            ("this weekend", Utc.ymd(2021, 5, 8), Utc.ymd(2021, 5, 8)),
+            ("next weekend", Utc.ymd(2021, 5, 15), Utc.ymd(2021, 5, 15)),
+            ("Last Weekend", Utc.ymd(2021, 5, 1), Utc.ymd(2021, 5, 1)),
+        ];
+
+        for &(input, want, want_sunday_first) in test_cases.iter() {
+            assert_eq!(
+                parse.relative_weekday(input, &now).unwrap().unwrap(),
+                want.and_hms(0, 0, 0),
+                "relative_weekday/{}",
+                input
+            );
+            assert_eq!(
+                sunday_first.relative_weekday(input, &now).unwrap().unwrap(),
+                want_sunday_first.and_hms(0, 0, 0),
+                "relative_weekday/sunday_first/{}",
+                input
+            );
+        }
+        assert_eq!(
+            Parse::new(&Utc, None)
+                .relative_weekday("next friday", &now)
+                .unwrap()
+                .unwrap(),
+            Utc.ymd(2021, 5, 7).and_hms(10, 0, 0)
+        );
+        // on a Sunday, this weekend is the day before for a week starting on Monday
+        let sunday = Utc.ymd(2021, 5, 9).and_hms(10, 0, 0);
+        assert_eq!(
+            parse
+                .relative_weekday("this weekend", &sunday)
+                .unwrap()
+                .unwrap(),
+            Utc.ymd(2021, 5, 8).and_hms(0, 0, 0)
+        );
+        assert_eq!(
+            sunday_first
+                .relative_weekday("this weekend", &sunday)
+                .unwrap()
+                .unwrap(),
+            Utc.ymd(2021, 5, 15).and_hms(0, 0, 0)
+        );
+        assert!(parse.relative_weekday("next month", &now).is_none());
+        assert!(parse.relative_weekday("next fooday", &now).is_none());
+        assert!(parse.relative_weekday("not-date-time", &now).is_none());
+    }
+
+    #[test]
+    fn iso_duration() {
+        let parse = Parse::new(&Utc, None);
+        let now = Utc.ymd(2021, 5, 5).and_hms(10, 0, 0);
+
+        let test_cases = [
+            ("PT2H", Utc.ymd(2021, 5, 5).and_hms(12, 0, 0)),
+            ("-PT2H", Utc.ymd(2021, 5, 5).and_hms(8, 0, 0)),
+            ("P1D", Utc.ymd(2021, 5, 6).and_hms(10, 0, 0)),
+            ("-P1D", Utc.ymd(2021, 5, 4).and_hms(10, 0, 0)),
+            ("+P1W", Utc.ymd(2021, 5, 12).and_hms(10, 0, 0)),
+            ("P2W", Utc.ymd(2021, 5, 19).and_hms(10, 0, 0)),
+            ("P1DT1H", Utc.ymd(2021, 5, 6).and_hms(11, 0, 0)),
+            ("PT1H30M15S", Utc.ymd(2021, 5, 5).and_hms(11, 30, 15)),
+            ("PT90M", Utc.ymd(2021, 5, 5).and_hms(11, 30, 0)),
+            ("PT0.5S", Utc.ymd(2021, 5, 5).and_hms_milli(10, 0, 0, 500)),
+            (
+                "-PT1.25S",
+                Utc.ymd(2021, 5, 5).and_hms_milli(9, 59, 58, 750),
+            ),
+        ];
+
+        for &(input, want) in test_cases.iter() {
+            assert_eq!(
+                parse.iso_duration(input, &now).unwrap().unwrap(),
+                want,
+                "iso_duration/{}",
+                input
+            )
+        }
+
+        for input in ["P1Y", "P1M", "-P1Y2M3D", "P1MT2H"] {
+            assert_eq!(
+                parse
+                    .iso_duration(input, &now)
+                    .unwrap()
+                    .unwrap_err()
+                    .to_string(),
+                format!(
+                    "{} has years or months, which are not a fixed length.",
+                    input
+                )
+            );
+        }
+        assert!(parse
+            .iso_duration("P99999999999999999W", &now)
+            .unwrap()
+            .is_err());
+        for input in [
+            "P",
+            "PT",
+            "-P",
+            "P1DT",
+            "PT1D",
+            "P1H",
+            "p1d",
+            "1D",
+            "not-date-time",
+        ] {
+            assert!(
+                parse.iso_duration(input, &now).is_none(),
+                "iso_duration/{}",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn business_day() {
+        let parse = Parse::new(&Utc, None);
+        let now = Utc.ymd(2021, 5, 5).and_hms(10, 0, 0);
+
+        let test_cases = [
+            ("EOD", Utc.ymd(2021, 5, 5).and_hms(23, 59, 59)),
+            ("bod", Utc.ymd(2021, 5, 5).and_hms(0, 0, 0)),
+            ("COB", Utc.ymd(2021, 5, 5).and_hms(17, 0, 0)),
+            ("2021-05-01 EOD", Utc.ymd(2021, 5, 1).and_hms(23, 59, 59)),
+            ("2021-05-01 BOD", Utc.ymd(2021, 5, 1).and_hms(0, 0, 0)),
+            ("2021-05-01 cob", Utc.ymd(2021, 5, 1).and_hms(17, 0, 0)),
+            ("May 1, 2021 COB", Utc.ymd(2021, 5, 1).and_hms(17, 0, 0)),
+            ("05/01/2021 EOD", Utc.ymd(2021, 5, 1).and_hms(23, 59, 59)),
+        ];
+
+        for &(input, want) in test_cases.iter() {
+            assert_eq!(
+                parse.business_day(input, &now).unwrap().unwrap(),
+                want,
+                "business_day/{}",
+                input
+            )
+        }
+
+        // the date is read in the parsing timezone
+        let tz = FixedOffset::west(8 * 3600);
+        assert_eq!(
+            Parse::new(&tz, None)
+                .business_day("2021-05-01 EOD", &now)
+                .unwrap()
+                .unwrap(),
+            Utc.ymd(2021, 5, 2).and_hms(7, 59, 59)
+        );
+
+        let cob_18 = Parse::new(&Utc, None).cob_hour(18);
+        assert_eq!(
+            cob_18.business_day("COB", &now).unwrap().unwrap(),
+            Utc.ymd(2021, 5, 5).and_hms(18, 0, 0)
+        );
+        assert!(parse.business_day("2021-02-30 EOD", &now).unwrap().is_err());
+        // only a date without a time or a zone comes before a shorthand
+        assert!(parse.business_day("EOD EOD", &now).unwrap().is_err());
+        assert!(parse.parse("EOD EOD").is_err());
+        assert!(parse.parse(&"EOD ".repeat(10_000)).is_err());
+        assert!(parse
+            .business_day("2021-05-01 10:30 EOD", &now)
+            .unwrap()
+            .is_err());
+        assert!(parse.parse("2021-05-01 10:30 EOD").is_err());
+        assert!(parse.parse("2021-05-01 10:00:00 +08:00 EOD").is_err());
+        assert!(parse.parse("2021-05-01 UTC EOD").is_err());
+        assert!(parse.business_day("not a date EOD", &now).unwrap().is_err());
+        assert!(parse.business_day("EODs", &now).is_none());
+        assert!(parse.business_day("not-date-time", &now).is_none());
+    }
+
+    #[test]
+    #[should_panic(expected = "24 is not a valid hour.")]
+    fn cob_hour_out_of_range() {
+        let _ = Parse::new(&Utc, None).cob_hour(24);
+    }
+
+    #[test]
+    fn week_of_year() {
+        let parse = Parse::new(&Utc, Some(NaiveTime::from_hms(0, 0, 0)));
+
+        let test_cases = [
+            ("Week 23 of 2021", Utc.ymd(2021, 6, 7).and_hms(0, 0, 0)),
+            ("2021 week 23", Utc.ymd(2021, 6, 7).and_hms(0, 0, 0)),
+            ("week 1 of 2021", Utc.ymd(2021, 1, 4).and_hms(0, 0, 0)),
+            ("2020 Week 53", Utc.ymd(2020, 12, 28).and_hms(0, 0, 0)),
+        ];
+
+        for &(input, want) in test_cases.iter() {
+            assert_eq!(
+                parse.week_of_year(input, &Utc::now()).unwrap().unwrap(),
+                want,
+                "week_of_year/{}",
+                input
+            )
+        }
+        assert!(parse.week_of_year("Week 0 of 2021", &Utc::now()).is_none());
+        assert!(parse.week_of_year("Week 54 of 2021", &Utc::now()).is_none());
+        assert!(parse.week_of_year("Week 53 of 2021", &Utc::now()).is_none());
+        assert!(parse.week_of_year("not-date-time", &Utc::now()).is_none());
+    }
+
+    #[test]
+    fn month_md_hms() {
+        let parse = Parse::new(&Utc, None);
+
+        let test_cases = [
+            (
+                "May 6 9:24 PM",
+                Utc.ymd(Utc::now().year(), 5, 6).and_hms(21, 24, 0),
+            ),
+            (
+                "May  6 9:24 PM",
+                Utc.ymd(Utc::now().year(), 5, 6).and_hms(21, 24, 0),
+            ),
+            (
+                "May 6 02:45:27",
+                Utc.ymd(Utc::now().year(), 5, 6).and_hms(2, 45, 27),
+            ),
+            (
+                "May  6 02:45:27",
+                Utc.ymd(Utc::now().year(), 5, 6).and_hms(2, 45, 27),
+            ),
+            (
+                "May 06 02:45:27",
+                Utc.ymd(Utc::now().year(), 5, 6).and_hms(2, 45, 27),
+            ),
+            (
+                "May 27 02:45:27",
+                Utc.ymd(Utc::now().year(), 5, 27).and_hms(2, 45, 27),
+            ),
+        ];
+
+        for &(input, want) in test_cases.iter() {
+            assert_eq!(
+                parse.month_md_hms(input, &Utc::now()).unwrap().unwrap(),
+                want,
+                "month_md_hms/{}",
+                input
+            )
+        }
+        assert!(parse.month_md_hms("not-date-time", &Utc::now()).is_none());
+    }
+
+    #[test]
+    fn month_mdy_hms() {
+        let parse = Parse::new(&Utc, None);
+
+        let test_cases = [
+            (
                 "May 8, 2009 5:57:51 PM",
                 Utc.ymd(2009, 5, 8).and_hms(17, 57, 51),
             ),
@@ -1267,12 +4815,24 @@ mod tests {
                 "May 02, 2021 15:51 UTC",
                 Utc.ymd(2021, 5, 2).and_hms(15, 51, 0),
             ),
+            (
+                "May 02, 2021 15:51:31.123456 UTC",
+                Utc.ymd(2021, 5, 2).and_hms_micro(15, 51, 31, 123456),
+            ),
+            (
+                "May 02, 2021 15:51:31.123456789 PST",
+                Utc.ymd(2021, 5, 2).and_hms_nano(23, 51, 31, 123456789),
+            ),
+            (
+                "May 02, 2021 15:51:31.123 +0800",
+                Utc.ymd(2021, 5, 2).and_hms_milli(7, 51, 31, 123),
+            ),
             (
                 "May 26, 2021, 12:49 AM PDT",
                 Utc.ymd(2021, 5, 26).and_hms(7, 49, 0),
             ),
             (
-                "September 17, 2012 at 10:09am PST",
+                "September 17, 2012 10:09am PST",
                 Utc.ymd(2012, 9, 17).and_hms(18, 9, 0),
             ),
         ];
@@ -1322,7 +4882,7 @@ mod tests {
         for &(input, want) in test_cases.iter() {
             assert_eq!(
                 parse
-                    .month_mdy(input)
+                    .month_mdy(input, &Utc::now())
                     .unwrap()
                     .unwrap()
                     .trunc_subsecs(0)
@@ -1333,7 +4893,7 @@ mod tests {
                 input
             )
         }
-        assert!(parse.month_mdy("not-date-time").is_none());
+        assert!(parse.month_mdy("not-date-time", &Utc::now()).is_none());
     }
 
     #[test]
@@ -1386,7 +4946,7 @@ mod tests {
         for &(input, want) in test_cases.iter() {
             assert_eq!(
                 parse
-                    .month_dmy(input)
+                    .month_dmy(input, &Utc::now())
                     .unwrap()
                     .unwrap()
                     .trunc_subsecs(0)
@@ -1397,7 +4957,7 @@ mod tests {
                 input
             )
         }
-        assert!(parse.month_dmy("not-date-time").is_none());
+        assert!(parse.month_dmy("not-date-time", &Utc::now()).is_none());
     }
 
     #[test]
@@ -1459,7 +5019,7 @@ mod tests {
         for &(input, want) in test_cases.iter() {
             assert_eq!(
                 parse
-                    .slash_mdy(input)
+                    .slash_mdy(input, &Utc::now())
                     .unwrap()
                     .unwrap()
                     .trunc_subsecs(0)
@@ -1470,7 +5030,7 @@ mod tests {
                 input
             )
         }
-        assert!(parse.slash_mdy("not-date-time").is_none());
+        assert!(parse.slash_mdy("not-date-time", &Utc::now()).is_none());
     }
 
     #[test]
@@ -1503,6 +5063,44 @@ mod tests {
         assert!(parse.slash_ymd_hms("not-date-time").is_none());
     }
 
+    #[test]
+    fn slash_ymd_date_order() {
+        let midnight = Some(NaiveTime::from_hms(0, 0, 0));
+        let ymd = Parse::new(&Utc, midnight).date_order(DateOrder::Ymd);
+        let ydm = Parse::new(&Utc, midnight).date_order(DateOrder::Ydm);
+
+        let test_cases = [
+            (
+                "2014/8/4",
+                Utc.ymd(2014, 8, 4).and_hms(0, 0, 0),
+                Utc.ymd(2014, 4, 8).and_hms(0, 0, 0),
+            ),
+            (
+                "2014/4/8",
+                Utc.ymd(2014, 4, 8).and_hms(0, 0, 0),
+                Utc.ymd(2014, 8, 4).and_hms(0, 0, 0),
+            ),
+            (
+                "2014/08/04 22:05",
+                Utc.ymd(2014, 8, 4).and_hms(22, 5, 0),
+                Utc.ymd(2014, 4, 8).and_hms(22, 5, 0),
+            ),
+            (
+                "2014/8/4 10:15 PM",
+                Utc.ymd(2014, 8, 4).and_hms(22, 15, 0),
+                Utc.ymd(2014, 4, 8).and_hms(22, 15, 0),
+            ),
+        ];
+
+        for &(input, want_ymd, want_ydm) in test_cases.iter() {
+            assert_eq!(ymd.parse(input).unwrap(), want_ymd, "ymd/{}", input);
+            assert_eq!(ydm.parse(input).unwrap(), want_ydm, "ydm/{}", input);
+        }
+        assert!(ymd.parse("2014/4/31").is_err());
+        assert!(ydm.parse("2014/30/4").is_ok());
+        assert!(ydm.parse("2014/4/30").is_err());
+    }
+
     #[test]
     fn slash_ymd() {
         let parse = Parse::new(&Utc, Some(Utc::now().time()));
@@ -1521,7 +5119,7 @@ mod tests {
         for &(input, want) in test_cases.iter() {
             assert_eq!(
                 parse
-                    .slash_ymd(input)
+                    .slash_ymd(input, &Utc::now())
                     .unwrap()
                     .unwrap()
                     .trunc_subsecs(0)
@@ -1532,7 +5130,7 @@ mod tests {
                 input
             )
         }
-        assert!(parse.slash_ymd("not-date-time").is_none());
+        assert!(parse.slash_ymd("not-date-time", &Utc::now()).is_none());
     }
 
     #[test]
@@ -1555,17 +5153,13 @@ mod tests {
                 "2014.03.30",
                 Utc.ymd(2014, 3, 30).and_time(Utc::now().time()),
             ),
-            (
-                "2014.03",
-                Utc.ymd(2014, 3, Utc::now().day())
-                    .and_time(Utc::now().time()),
-            ),
+            ("2014.03", Utc.ymd(2014, 3, 1).and_time(Utc::now().time())),
         ];
 
         for &(input, want) in test_cases.iter() {
             assert_eq!(
                 parse
-                    .dot_mdy_or_ymd(input)
+                    .dot_mdy_or_ymd(input, &Utc::now())
                     .unwrap()
                     .unwrap()
                     .trunc_subsecs(0)
@@ -1576,7 +5170,14 @@ mod tests {
                 input
             )
         }
-        assert!(parse.dot_mdy_or_ymd("not-date-time").is_none());
+        assert!(parse.dot_mdy_or_ymd("not-date-time", &Utc::now()).is_none());
+        assert!(parse
+            .dot_mdy_or_ymd("version 2014.03.30", &Utc::now())
+            .is_none());
+        assert!(parse
+            .dot_mdy_or_ymd("2014.03.30 extra", &Utc::now())
+            .is_none());
+        assert!(parse.dot_mdy_or_ymd("2014-03-30", &Utc::now()).is_none());
     }
 
     #[test]
@@ -1597,16 +5198,38 @@ mod tests {
             )
         }
         assert!(parse.mysql_log_timestamp("not-date-time").is_none());
+        assert!(parse
+            .mysql_log_timestamp("prefix 171113 14:14:20 suffix")
+            .is_none());
+        assert!(parse.mysql_log_timestamp("20171113 14:14:20").is_none());
     }
 
     #[test]
     fn chinese_ymd_hms() {
         let parse = Parse::new(&Utc, None);
 
-        let test_cases = [(
-            "2014年04月08日11时25分18秒",
-            Utc.ymd(2014, 4, 8).and_hms(11, 25, 18),
-        )];
+        let test_cases = [
+            (
+                "2014年04月08日11时25分18秒",
+                Utc.ymd(2014, 4, 8).and_hms(11, 25, 18),
+            ),
+            (
+                "2014年04月08日 11时25分18秒",
+                Utc.ymd(2014, 4, 8).and_hms(11, 25, 18),
+            ),
+            (
+                "2014年04月08日11时25分18.5秒",
+                Utc.ymd(2014, 4, 8).and_hms_milli(11, 25, 18, 500),
+            ),
+            (
+                "2014年04月08日 11时25分18.123456789秒",
+                Utc.ymd(2014, 4, 8).and_hms_nano(11, 25, 18, 123456789),
+            ),
+            (
+                "2014年 4月 8日 11時25分18秒",
+                Utc.ymd(2014, 4, 8).and_hms(11, 25, 18),
+            ),
+        ];
 
         for &(input, want) in test_cases.iter() {
             assert_eq!(
@@ -1616,22 +5239,76 @@ mod tests {
                 input
             )
         }
+        assert!(parse
+            .chinese_ymd_hms("2014年02月30日11时25分18秒")
+            .is_none());
         assert!(parse.chinese_ymd_hms("not-date-time").is_none());
     }
 
+    #[test]
+    fn chinese_ymd_meridiem_hms() {
+        let parse = Parse::new(&Utc, None);
+
+        let test_cases = [
+            (
+                "2014年04月08日上午11时25分18秒",
+                Utc.ymd(2014, 4, 8).and_hms(11, 25, 18),
+            ),
+            (
+                "2014年04月08日 上午9时25分",
+                Utc.ymd(2014, 4, 8).and_hms(9, 25, 0),
+            ),
+            (
+                "2014年04月08日 下午3时25分",
+                Utc.ymd(2014, 4, 8).and_hms(15, 25, 0),
+            ),
+            (
+                "2014年04月08日下午3时",
+                Utc.ymd(2014, 4, 8).and_hms(15, 0, 0),
+            ),
+            (
+                "2014年04月08日下午12时",
+                Utc.ymd(2014, 4, 8).and_hms(12, 0, 0),
+            ),
+            (
+                "2014年04月08日上午12时",
+                Utc.ymd(2014, 4, 8).and_hms(0, 0, 0),
+            ),
+        ];
+
+        for &(input, want) in test_cases.iter() {
+            assert_eq!(
+                parse.chinese_ymd_meridiem_hms(input).unwrap().unwrap(),
+                want,
+                "chinese_ymd_meridiem_hms/{}",
+                input
+            )
+        }
+        assert!(parse
+            .chinese_ymd_meridiem_hms("2014年04月08日下午13时")
+            .is_none());
+        assert!(parse.chinese_ymd_meridiem_hms("not-date-time").is_none());
+    }
+
     #[test]
     fn chinese_ymd() {
         let parse = Parse::new(&Utc, Some(Utc::now().time()));
 
-        let test_cases = [(
-            "2014年04月08日",
-            Utc.ymd(2014, 4, 8).and_time(Utc::now().time()),
-        )];
+        let test_cases = [
+            (
+                "2014年04月08日",
+                Utc.ymd(2014, 4, 8).and_time(Utc::now().time()),
+            ),
+            (
+                "2014年 4月 8日",
+                Utc.ymd(2014, 4, 8).and_time(Utc::now().time()),
+            ),
+        ];
 
         for &(input, want) in test_cases.iter() {
             assert_eq!(
                 parse
-                    .chinese_ymd(input)
+                    .chinese_ymd(input, &Utc::now())
                     .unwrap()
                     .unwrap()
                     .trunc_subsecs(0)
@@ -1642,6 +5319,1151 @@ mod tests {
                 input
             )
         }
-        assert!(parse.chinese_ymd("not-date-time").is_none());
+        assert!(parse.chinese_ymd("not-date-time", &Utc::now()).is_none());
+    }
+
+    #[test]
+    fn korean_ymd_hms() {
+        let parse = Parse::new(&Utc, None);
+
+        let test_cases = [
+            (
+                "2014년04월08일 11시25분18초",
+                Utc.ymd(2014, 4, 8).and_hms(11, 25, 18),
+            ),
+            (
+                "2014년04월08일11시25분18초",
+                Utc.ymd(2014, 4, 8).and_hms(11, 25, 18),
+            ),
+            (
+                "2014년 4월 8일 11시 25분 18.5초",
+                Utc.ymd(2014, 4, 8).and_hms_milli(11, 25, 18, 500),
+            ),
+        ];
+
+        for &(input, want) in test_cases.iter() {
+            assert_eq!(
+                parse.korean_ymd_hms(input).unwrap().unwrap(),
+                want,
+                "korean_ymd_hms/{}",
+                input
+            )
+        }
+        assert!(parse.korean_ymd_hms("2014年04月08日11时25分18秒").is_none());
+        assert!(parse.korean_ymd_hms("not-date-time").is_none());
+    }
+
+    #[test]
+    fn korean_ymd() {
+        let parse = Parse::new(&Utc, Some(NaiveTime::from_hms(0, 0, 0)));
+
+        let test_cases = [
+            ("2014년04월08일", Utc.ymd(2014, 4, 8).and_hms(0, 0, 0)),
+            ("2014년 4월 8일", Utc.ymd(2014, 4, 8).and_hms(0, 0, 0)),
+        ];
+
+        for &(input, want) in test_cases.iter() {
+            assert_eq!(
+                parse.korean_ymd(input, &Utc::now()).unwrap().unwrap(),
+                want,
+                "korean_ymd/{}",
+                input
+            )
+        }
+        assert!(parse.korean_ymd("2014년02월30일", &Utc::now()).is_none());
+        assert!(parse.korean_ymd("not-date-time", &Utc::now()).is_none());
+    }
+
+    #[test]
+    fn allow_12_hour() {
+        let parse = Parse::new(&Utc, None).allow_12_hour(false);
+
+        let test_cases = [
+            "2014-04-26 05:24:37 PM",
+            "4:00pm",
+            "6:00 AM PST",
+            "11AM",
+            "Jan 28 at 10:10 am",
+            "May 8, 2009 5:57:51 PM",
+            "September 17, 2012 at 10:09am PST",
+            "4/8/2014 10:15 PM",
+            "2014/04/08 10:15 PM",
+            "2014年04月08日下午3时25分",
+        ];
+
+        for &input in test_cases.iter() {
+            assert!(
+                Parse::new(&Utc, None).parse(input).is_ok(),
+                "allow_12_hour/{}",
+                input
+            );
+            assert!(parse.parse(input).is_err(), "allow_12_hour/{}", input)
+        }
+        assert_eq!(
+            parse.parse("2021-04-30 21:14:10").unwrap(),
+            Utc.ymd(2021, 4, 30).and_hms(21, 14, 10)
+        );
+        assert_eq!(
+            parse.parse("4/8/2014 22:05").unwrap(),
+            Utc.ymd(2014, 4, 8).and_hms(22, 5, 0)
+        );
+    }
+
+    #[test]
+    fn getters() {
+        let tz = FixedOffset::west(8 * 3600);
+        let parse = Parse::new(&tz, Some(NaiveTime::from_hms(9, 30, 0)));
+        assert_eq!(parse.timezone(), &tz);
+        assert_eq!(parse.default_time(), Some(NaiveTime::from_hms(9, 30, 0)));
+
+        let owned = OwnedParse::new_owned(Utc, None);
+        assert_eq!(owned.timezone(), &Utc);
+        assert_eq!(owned.default_time(), None);
+    }
+
+    #[test]
+    fn format_order() {
+        let parse = Parse::new(&Utc, Some(NaiveTime::from_hms(0, 0, 0)));
+        let reordered = parse.clone().format_order(&[
+            FormatKind::MysqlLogTimestamp,
+            FormatKind::SlashMdyHms,
+            FormatKind::MysqlLogTimestamp,
+        ]);
+
+        assert_eq!(reordered.format_order.len(), FormatKind::ALL.len());
+        assert_eq!(reordered.format_order[0], FormatKind::MysqlLogTimestamp);
+        assert_eq!(reordered.format_order[1], FormatKind::SlashMdyHms);
+        assert_eq!(reordered.format_order[2], FormatKind::Custom);
+        assert_eq!(reordered.format_order[3], FormatKind::UnixTimestamp);
+
+        let test_cases = [
+            "1511648546",
+            "2017-11-25T22:34:50Z",
+            "2021-05-14T18:51:00 GMT",
+            "2021-04-30 21:14:10",
+            "4:00pm",
+            "May 8, 2009 5:57:51 PM",
+            "03/19/2012 10:11:59",
+            "171113 14:14:20",
+            "2014年04月08日",
+        ];
+
+        for &input in test_cases.iter() {
+            assert_eq!(
+                reordered.parse(input).unwrap(),
+                parse.parse(input).unwrap(),
+                "format_order/{}",
+                input
+            )
+        }
+        assert!(reordered.parse("not-date-time").is_err());
+    }
+
+    #[test]
+    fn allowed_formats() {
+        let parse = Parse::new(&Utc, None)
+            .allowed_formats(&[FormatKind::Rfc3339, FormatKind::UnixTimestamp]);
+
+        assert_eq!(
+            parse.format_order.as_ref(),
+            &[FormatKind::UnixTimestamp, FormatKind::Rfc3339]
+        );
+        assert_eq!(
+            parse.parse("2021-05-01T01:17:02Z").unwrap(),
+            Utc.ymd(2021, 5, 1).and_hms(1, 17, 2)
+        );
+        assert_eq!(
+            parse.parse("1511648546").unwrap(),
+            Utc.ymd(2017, 11, 25).and_hms(22, 22, 26)
+        );
+        for input in [
+            "05/01/2021",
+            "05/01/2021 10:00:00",
+            "2021/05/01",
+            "2021-05-01 10:00:00",
+            "May 1, 2021",
+        ] {
+            assert!(parse.parse(input).is_err(), "allowed_formats/{}", input);
+            assert!(!parse.is_date(input), "allowed_formats/is_date/{}", input);
+            assert!(
+                parse.parse_all(input).is_empty(),
+                "allowed_formats/all/{}",
+                input
+            );
+        }
+
+        // reordering keeps the formats that are not allowed out, whichever is set first
+        let reordered = Parse::new(&Utc, None)
+            .format_order(&[FormatKind::SlashMdy])
+            .allowed_formats(&[FormatKind::Rfc3339]);
+        assert_eq!(reordered.format_order.as_ref(), &[FormatKind::Rfc3339]);
+        let reordered = Parse::new(&Utc, None)
+            .allowed_formats(&[FormatKind::Rfc3339])
+            .format_order(&[FormatKind::SlashMdy]);
+        assert_eq!(reordered.format_order.as_ref(), &[FormatKind::Rfc3339]);
+        assert!(reordered.parse("05/01/2021").is_err());
+    }
+
+    #[test]
+    fn year_bounds() {
+        let parse = Parse::new(&Utc, Some(NaiveTime::from_hms(0, 0, 0))).year_bounds(1900..=2100);
+
+        let test_cases = [
+            ("1900-01-01", Utc.ymd(1900, 1, 1).and_hms(0, 0, 0)),
+            ("2021-05-01 10:00:00", Utc.ymd(2021, 5, 1).and_hms(10, 0, 0)),
+            (
+                "2100-12-31 23:59:59",
+                Utc.ymd(2100, 12, 31).and_hms(23, 59, 59),
+            ),
+        ];
+        for &(input, want) in test_cases.iter() {
+            assert_eq!(parse.parse(input).unwrap(), want, "year_bounds/{}", input)
+        }
+
+        let test_cases = [
+            ("1000-01-01", 1000),
+            ("1899-12-31 23:59:59", 1899),
+            ("2101-01-01 00:00:00", 2101),
+            ("May 1, 3021", 3021),
+            ("4133980800", 2101),
+        ];
+        for &(input, year) in test_cases.iter() {
+            let err = parse.parse(input).unwrap_err();
+            assert_eq!(
+                err.downcast_ref::<ParseError>(),
+                Some(&ParseError::YearOutOfBounds(year)),
+                "year_bounds/{}",
+                input
+            )
+        }
+
+        // the year is read in the parsing timezone
+        let east = FixedOffset::east(9 * 3600);
+        let parse = Parse::new(&east, None).year_bounds(1900..=2100);
+        assert!(parse.parse("1899-12-31 20:00:00 +00:00").is_ok());
+
+        // any year is accepted without bounds
+        assert!(Parse::new(&Utc, None).parse("1000-01-01 00:00:00").is_ok());
+    }
+
+    #[test]
+    fn at_separator() {
+        let parse = Parse::new(&Utc, None);
+
+        let test_cases = [
+            (
+                "September 17, 2012 at 10:09am",
+                Utc.ymd(2012, 9, 17).and_hms(10, 9, 0),
+            ),
+            (
+                "September 17, 2012 at 10:09am PST",
+                Utc.ymd(2012, 9, 17).and_hms(18, 9, 0),
+            ),
+            (
+                "May 6 at 9:24 PM",
+                Utc.ymd(Utc::now().year(), 5, 6).and_hms(21, 24, 0),
+            ),
+            (
+                "May 27 at 02:45:27",
+                Utc.ymd(Utc::now().year(), 5, 27).and_hms(2, 45, 27),
+            ),
+            (
+                "12 Feb 2006 at 19:17",
+                Utc.ymd(2006, 2, 12).and_hms(19, 17, 0),
+            ),
+            (
+                "2021-04-30 at 21:14:10",
+                Utc.ymd(2021, 4, 30).and_hms(21, 14, 10),
+            ),
+        ];
+
+        for &(input, want) in test_cases.iter() {
+            assert_eq!(parse.parse(input).unwrap(), want, "at_separator/{}", input)
+        }
+        assert_eq!(normalize("Sat, 17 Sep 2012"), "Sat, 17 Sep 2012");
+        assert_eq!(
+            normalize("September 17, 2012 at10:09am"),
+            "September 17, 2012 at10:09am"
+        );
+        assert!(parse.parse("September 17, 2012 atat 10:09am").is_err());
+    }
+
+    #[test]
+    fn long_fraction() {
+        let parse = Parse::new(&Utc, None);
+
+        let test_cases = [
+            (
+                "2014-04-26 17:24:37.123456789012",
+                Utc.ymd(2014, 4, 26).and_hms_nano(17, 24, 37, 123456789),
+            ),
+            (
+                "2014-04-26 17:24:37.1234567899",
+                Utc.ymd(2014, 4, 26).and_hms_nano(17, 24, 37, 123456789),
+            ),
+            (
+                "2019-11-29 08:08:05.123456789012-08",
+                Utc.ymd(2019, 11, 29).and_hms_nano(16, 8, 5, 123456789),
+            ),
+            (
+                "2015-09-30 18:48:56.352727151234 UTC",
+                Utc.ymd(2015, 9, 30).and_hms_nano(18, 48, 56, 352727151),
+            ),
+            (
+                "03/19/2012 10:11:59.318636900000",
+                Utc.ymd(2012, 3, 19).and_hms_nano(10, 11, 59, 318636900),
+            ),
+            (
+                "14 May 2019 19:11:40.164000000000",
+                Utc.ymd(2019, 5, 14).and_hms_nano(19, 11, 40, 164000000),
+            ),
+        ];
+
+        for &(input, want) in test_cases.iter() {
+            assert_eq!(parse.parse(input).unwrap(), want, "long_fraction/{}", input)
+        }
+    }
+
+    #[test]
+    fn parse_first_in_text() {
+        let parse = Parse::new(&Utc, Some(NaiveTime::from_hms(0, 0, 0)));
+
+        let test_cases = [
+            (
+                "Backup completed at 2021-05-01 10:00:00 UTC successfully",
+                Utc.ymd(2021, 5, 1).and_hms(10, 0, 0),
+                "2021-05-01 10:00:00 UTC",
+            ),
+            (
+                "The release is planned for May 25, 2021, pending review.",
+                Utc.ymd(2021, 5, 25).and_hms(0, 0, 0),
+                "May 25, 2021",
+            ),
+            (
+                "deployed on 2021-02-21.",
+                Utc.ymd(2021, 2, 21).and_hms(0, 0, 0),
+                "2021-02-21",
+            ),
+            (
+                "[2021-04-30T21:14:10Z] server started",
+                Utc.ymd(2021, 4, 30).and_hms(21, 14, 10),
+                "2021-04-30T21:14:10Z",
+            ),
+            (
+                "Last login: Wed Jun  2 06:31:39 PDT 2021 from 10.0.0.1",
+                Utc.ymd(2021, 6, 2).and_hms(13, 31, 39),
+                "Wed Jun  2 06:31:39 PDT 2021",
+            ),
+            (
+                "2021-05-01 first, then 2021-06-01",
+                Utc.ymd(2021, 5, 1).and_hms(0, 0, 0),
+                "2021-05-01",
+            ),
+            (
+                "备份时间 2014年04月08日 已完成",
+                Utc.ymd(2014, 4, 8).and_hms(0, 0, 0),
+                "2014年04月08日",
+            ),
+        ];
+
+        for &(input, want, matched) in test_cases.iter() {
+            let (parsed, range) = parse.parse_first_in_text(input).unwrap();
+            assert_eq!(parsed, want, "parse_first_in_text/{}", input);
+            assert_eq!(&input[range], matched, "parse_first_in_text/{}", input);
+        }
+        // relative weekdays and business day shorthands, which have no fixed date
+        for (input, matched) in [
+            ("see you last mon then", "last mon"),
+            ("Backups run this weekend, as usual", "this weekend"),
+            ("Ship it by next Friday.", "next Friday"),
+            ("Reply by EOD then", "EOD"),
+        ] {
+            let (parsed, range) = parse.parse_first_in_text(input).unwrap();
+            assert_eq!(&input[range], matched, "parse_first_in_text/{}", input);
+            assert_eq!(
+                parsed,
+                parse.parse(matched).unwrap(),
+                "parse_first_in_text/{}",
+                input
+            );
+        }
+        // `this` alone does not start a datetime, so the search goes on past it
+        let input = "this is due 2021-05-01 COB then";
+        let (parsed, range) = parse.parse_first_in_text(input).unwrap();
+        assert_eq!(parsed, Utc.ymd(2021, 5, 1).and_hms(17, 0, 0));
+        assert_eq!(&input[range], "2021-05-01 COB");
+        assert!(parse.parse_first_in_text("no datetime in here").is_err());
+        assert!(parse.parse_first_in_text("next on the list").is_err());
+        assert!(parse.parse_first_in_text("build2021-05-01").is_err());
+        assert!(parse.parse_first_in_text("").is_err());
+    }
+
+    #[test]
+    fn parse_instrumented() {
+        let parse = Parse::new(&Utc, None);
+
+        let (parsed, stats) = parse.parse_instrumented("2021-05-01T01:17:02.604456Z");
+        assert_eq!(
+            parsed.unwrap(),
+            Utc.ymd(2021, 5, 1).and_hms_micro(1, 17, 2, 604456)
+        );
+        assert_eq!(stats.matched, Some(FormatKind::Rfc3339));
+        // every format before rfc3339 in the default order is tried, apart from the families that
+        // the input does not start like
+        let position = FormatKind::ALL
+            .iter()
+            .position(|&kind| kind == FormatKind::Rfc3339)
+            .unwrap();
+        assert!(stats.attempts >= 1 && stats.attempts <= position + 1);
+
+        // putting rfc3339 first makes it the only attempt
+        let reordered = Parse::new(&Utc, None).format_order(&[FormatKind::Rfc3339]);
+        let (_, stats) = reordered.parse_instrumented("2021-05-01T01:17:02.604456Z");
+        assert_eq!(
+            stats,
+            ParseStats {
+                attempts: 1,
+                matched: Some(FormatKind::Rfc3339)
+            }
+        );
+
+        let (parsed, stats) = parse.parse_instrumented("2021-02-30");
+        assert!(parsed.is_err());
+        assert_eq!(stats.matched, Some(FormatKind::Ymd));
+
+        let (parsed, stats) = parse.parse_instrumented("not a date");
+        assert_eq!(
+            parsed.unwrap_err().to_string(),
+            "not a date did not match any formats."
+        );
+        assert_eq!(stats.matched, None);
+        assert!(stats.attempts > 0);
+
+        let (parsed, stats) = parse.parse_instrumented("");
+        assert!(parsed.is_err());
+        assert_eq!(stats, ParseStats::default());
+    }
+
+    #[test]
+    fn parse_prefix() {
+        let parse = Parse::new(&Utc, Some(NaiveTime::from_hms(0, 0, 0)));
+
+        let test_cases = [
+            (
+                "2021-05-01 extra text",
+                Utc.ymd(2021, 5, 1).and_hms(0, 0, 0),
+                " extra text",
+            ),
+            (
+                "2021-05-01 10:00:00 UTC extra text",
+                Utc.ymd(2021, 5, 1).and_hms(10, 0, 0),
+                " extra text",
+            ),
+            (
+                "May 25, 2021, 10 people attended",
+                Utc.ymd(2021, 5, 25).and_hms(0, 0, 0),
+                ", 10 people attended",
+            ),
+            (
+                "1511648546 GET /index.html",
+                Utc.ymd(2017, 11, 25).and_hms(22, 22, 26),
+                " GET /index.html",
+            ),
+            ("2021-05-01", Utc.ymd(2021, 5, 1).and_hms(0, 0, 0), ""),
+            // words that an accepted format ends with are part of the datetime
+            (
+                "2021-05-01 COB then",
+                Utc.ymd(2021, 5, 1).and_hms(17, 0, 0),
+                " then",
+            ),
+            (
+                "2021-05-01 EOD then",
+                Utc.ymd(2021, 5, 1).and_hms(23, 59, 59),
+                " then",
+            ),
+            (
+                "2021-05-01 bod, then",
+                Utc.ymd(2021, 5, 1).and_hms(0, 0, 0),
+                ", then",
+            ),
+        ];
+
+        for &(input, want, want_rest) in test_cases.iter() {
+            let (parsed, rest) = parse.parse_prefix(input).unwrap();
+            assert_eq!(parsed, want, "parse_prefix/{}", input);
+            assert_eq!(rest, want_rest, "parse_prefix/{}", input);
+        }
+        for input in ["this weekend", "next weekend", "Last Weekend", "next week"] {
+            let text = format!("{} then", input);
+            let (parsed, rest) = parse.parse_prefix(&text).unwrap();
+            assert_eq!(
+                parsed,
+                parse.parse(input).unwrap(),
+                "parse_prefix/{}",
+                input
+            );
+            assert_eq!(rest, " then", "parse_prefix/{}", input);
+        }
+        // the words are only part of the datetime while their format is accepted
+        let (parsed, rest) = Parse::new(&Utc, Some(NaiveTime::from_hms(0, 0, 0)))
+            .allowed_formats(&[FormatKind::Ymd])
+            .parse_prefix("2021-05-01 COB then")
+            .unwrap();
+        assert_eq!(parsed, Utc.ymd(2021, 5, 1).and_hms(0, 0, 0));
+        assert_eq!(rest, " COB then");
+        assert!(parse.parse_prefix("on 2021-05-01").is_err());
+        assert!(parse.parse_prefix("").is_err());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn parse_lines() {
+        let parse = Parse::new(&Utc, None);
+        let input =
+            "2021-05-01 10:00:00 UTC\n  1511648546  \r\nnot a date\n\n2021-05-01\n2021-05-02\n";
+
+        let results: Vec<_> = parse.parse_lines(std::io::Cursor::new(input)).collect();
+        let lines: Vec<_> = results.iter().map(|(line, _)| line.as_str()).collect();
+        assert_eq!(
+            lines,
+            [
+                "2021-05-01 10:00:00 UTC",
+                "  1511648546  ",
+                "not a date",
+                "",
+                "2021-05-01",
+                "2021-05-02"
+            ]
+        );
+        assert_eq!(
+            results[0].1.as_ref().unwrap(),
+            &Utc.ymd(2021, 5, 1).and_hms(10, 0, 0)
+        );
+        assert_eq!(
+            results[1].1.as_ref().unwrap(),
+            &Utc.ymd(2017, 11, 25).and_hms(22, 22, 26)
+        );
+        assert!(results[2].1.is_err());
+        assert_eq!(
+            results[3]
+                .1
+                .as_ref()
+                .unwrap_err()
+                .downcast_ref::<ParseError>(),
+            Some(&ParseError::EmptyInput)
+        );
+        // date-only lines fall back to the same current time
+        let first = results[4].1.as_ref().unwrap();
+        let second = results[5].1.as_ref().unwrap();
+        assert_eq!(first.time(), second.time());
+        assert_eq!(*second - *first, chrono::Duration::days(1));
+
+        // a line that is not valid utf-8 cannot be read, and ends the stream
+        let input: &[u8] = b"2021-05-01 10:00:00 UTC\n\xff\n2021-05-02\n";
+        let results: Vec<_> = parse.parse_lines(input).collect();
+        assert_eq!(results.len(), 2);
+        assert!(results[0].1.is_ok());
+        assert_eq!(results[1].0, "");
+        assert!(results[1]
+            .1
+            .as_ref()
+            .unwrap_err()
+            .to_string()
+            .starts_with("line 2 could not be read: "));
+    }
+
+    #[test]
+    fn trailing_description() {
+        let parse = Parse::new(&Utc, Some(NaiveTime::from_hms(0, 0, 0)));
+
+        let test_cases = [
+            (
+                "2017-11-25 13:31:15 PST (Pacific Standard Time)",
+                Utc.ymd(2017, 11, 25).and_hms(21, 31, 15),
+            ),
+            (
+                "2021-04-30 21:14:10 (server time)",
+                Utc.ymd(2021, 4, 30).and_hms(21, 14, 10),
+            ),
+            (
+                "May 02, 2021 15:51 UTC (Coordinated Universal Time)",
+                Utc.ymd(2021, 5, 2).and_hms(15, 51, 0),
+            ),
+            ("2021-02-21 (Sunday)", Utc.ymd(2021, 2, 21).and_hms(0, 0, 0)),
+            (
+                "1511648546 (unix timestamp)",
+                Utc.ymd(2017, 11, 25).and_hms(22, 22, 26),
+            ),
+            (
+                "Wed, 02 Jun 2021 06:31:39 GMT  (Greenwich Mean Time)",
+                Utc.ymd(2021, 6, 2).and_hms(6, 31, 39),
+            ),
+        ];
+
+        for &(input, want) in test_cases.iter() {
+            assert_eq!(
+                parse.parse(input).unwrap(),
+                want,
+                "trailing_description/{}",
+                input
+            )
+        }
+        assert!(parse.parse("(Pacific Standard Time)").is_err());
+        assert!(parse.parse("2021-04-30 (server) 21:14:10").is_err());
+        assert!(parse.parse("2021-04-30 21:14:10 (UTC+02:00)").is_err());
+    }
+
+    #[test]
+    fn dotted_time() {
+        let parse = Parse::new(&Utc, None);
+
+        let test_cases = [
+            (
+                "2012-03-19 10.11.59",
+                Utc.ymd(2012, 3, 19).and_hms(10, 11, 59),
+            ),
+            ("2012-03-19 10.11", Utc.ymd(2012, 3, 19).and_hms(10, 11, 0)),
+            (
+                "2012-03-19 10.11.59 PST",
+                Utc.ymd(2012, 3, 19).and_hms(18, 11, 59),
+            ),
+            (
+                "2012-03-19 10.11.59+08",
+                Utc.ymd(2012, 3, 19).and_hms(2, 11, 59),
+            ),
+            (
+                "2012-03-19 10.11 pm",
+                Utc.ymd(2012, 3, 19).and_hms(22, 11, 0),
+            ),
+            (
+                "2012/03/19 10.11.59",
+                Utc.ymd(2012, 3, 19).and_hms(10, 11, 59),
+            ),
+            (
+                "03/19/2012 10.11.59",
+                Utc.ymd(2012, 3, 19).and_hms(10, 11, 59),
+            ),
+        ];
+
+        for &(input, want) in test_cases.iter() {
+            assert_eq!(parse.parse(input).unwrap(), want, "dotted_time/{}", input)
+        }
+        // dotted dates are not times
+        assert_eq!(
+            Parse::new(&Utc, Some(NaiveTime::from_hms(0, 0, 0)))
+                .parse("2014.03.30")
+                .unwrap(),
+            Utc.ymd(2014, 3, 30).and_hms(0, 0, 0)
+        );
+        assert!(parse.parse("2012-03-19 10.11.59.123").is_err());
+        assert!(parse.parse("19 May 2012 10.11.59").is_err());
+    }
+
+    #[test]
+    fn end_of_day() {
+        let parse = Parse::new(&Utc, None);
+
+        let test_cases = [
+            ("2021-05-01T24:00:00", Utc.ymd(2021, 5, 2).and_hms(0, 0, 0)),
+            ("2021-05-01T24:00:00Z", Utc.ymd(2021, 5, 2).and_hms(0, 0, 0)),
+            (
+                "2021-05-01T24:00:00+02:00",
+                Utc.ymd(2021, 5, 1).and_hms(22, 0, 0),
+            ),
+            ("2021-05-01 24:00", Utc.ymd(2021, 5, 2).and_hms(0, 0, 0)),
+            (
+                "2021-05-01 24:00:00.000",
+                Utc.ymd(2021, 5, 2).and_hms(0, 0, 0),
+            ),
+            (
+                "2021-05-01 24:00:00 PST",
+                Utc.ymd(2021, 5, 2).and_hms(8, 0, 0),
+            ),
+            ("2021-12-31T24:00:00Z", Utc.ymd(2022, 1, 1).and_hms(0, 0, 0)),
+            ("2020-02-28 24:00:00", Utc.ymd(2020, 2, 29).and_hms(0, 0, 0)),
+        ];
+
+        for &(input, want) in test_cases.iter() {
+            assert_eq!(parse.parse(input).unwrap(), want, "end_of_day/{}", input)
+        }
+        for input in [
+            "2021-05-01T24:00:01",
+            "2021-05-01T24:30:00",
+            "2021-05-01 24:00:00.5",
+            "2021-05-01T25:00:00Z",
+            "2021-02-30T24:00:00",
+        ] {
+            assert!(parse.parse(input).is_err(), "end_of_day/{}", input);
+        }
+    }
+
+    #[test]
+    fn leading_weekday() {
+        let parse = Parse::new(&Utc, Some(NaiveTime::from_hms(0, 0, 0)));
+
+        let test_cases = [
+            (
+                "Wed Jun 02 2021 06:31:39 GMT-0700 (Pacific Daylight Time)",
+                Utc.ymd(2021, 6, 2).and_hms(13, 31, 39),
+            ),
+            (
+                "Thu Jul 01 2021 09:00:00 GMT+0200 (Central European Summer Time)",
+                Utc.ymd(2021, 7, 1).and_hms(7, 0, 0),
+            ),
+            (
+                "Wednesday, May 12, 2021 16:44 UTC",
+                Utc.ymd(2021, 5, 12).and_hms(16, 44, 0),
+            ),
+            ("Tue, May 25, 2021", Utc.ymd(2021, 5, 25).and_hms(0, 0, 0)),
+            // the weekday is kept when the date is not `Mon dd yyyy`
+            (
+                "Wed Jun  2 06:31:39 2021",
+                Utc.ymd(2021, 6, 2).and_hms(6, 31, 39),
+            ),
+            (
+                "Wed, 02 Jun 2021 06:31:39 GMT",
+                Utc.ymd(2021, 6, 2).and_hms(6, 31, 39),
+            ),
+        ];
+
+        for &(input, want) in test_cases.iter() {
+            assert_eq!(
+                parse.parse(input).unwrap(),
+                want,
+                "leading_weekday/{}",
+                input
+            )
+        }
+    }
+
+    #[test]
+    fn collapse_whitespace() {
+        let parse = Parse::new(&Utc, Some(NaiveTime::from_hms(0, 0, 0)));
+
+        let test_cases = [
+            (
+                "2021-04-30   21:14:10",
+                Utc.ymd(2021, 4, 30).and_hms(21, 14, 10),
+            ),
+            (
+                "2021-04-30\t21:14:10  UTC",
+                Utc.ymd(2021, 4, 30).and_hms(21, 14, 10),
+            ),
+            (
+                "May  8,  2009  5:57:51  PM",
+                Utc.ymd(2009, 5, 8).and_hms(17, 57, 51),
+            ),
+            (
+                "Wed,  02 Jun  2021   06:31:39 GMT",
+                Utc.ymd(2021, 6, 2).and_hms(6, 31, 39),
+            ),
+            (
+                "Tue, 1 Jul  2003 10:52:37   +0200",
+                Utc.ymd(2003, 7, 1).and_hms(8, 52, 37),
+            ),
+            (
+                "03/19/2012   10:11:59",
+                Utc.ymd(2012, 3, 19).and_hms(10, 11, 59),
+            ),
+            ("12   Feb  2006", Utc.ymd(2006, 2, 12).and_hms(0, 0, 0)),
+            (
+                "2017-11-25 13:31:15   PST  (Pacific Standard Time)",
+                Utc.ymd(2017, 11, 25).and_hms(21, 31, 15),
+            ),
+        ];
+
+        for &(input, want) in test_cases.iter() {
+            assert_eq!(
+                parse.parse(input).unwrap(),
+                want,
+                "collapse_whitespace/{}",
+                input
+            )
+        }
+    }
+
+    #[test]
+    fn chinese_weekday() {
+        let parse = Parse::new(&Utc, Some(NaiveTime::from_hms(0, 0, 0)));
+
+        let test_cases = [
+            (
+                "星期二 2014年04月08日 下午3时",
+                Utc.ymd(2014, 4, 8).and_hms(15, 0, 0),
+            ),
+            (
+                "2014年04月08日星期二下午3时25分",
+                Utc.ymd(2014, 4, 8).and_hms(15, 25, 0),
+            ),
+            (
+                "2014年04月08日 星期二 上午9时",
+                Utc.ymd(2014, 4, 8).and_hms(9, 0, 0),
+            ),
+            (
+                "2014年04月08日 星期二 下午12时",
+                Utc.ymd(2014, 4, 8).and_hms(12, 0, 0),
+            ),
+            (
+                "2014年04月08日 星期二 上午12时",
+                Utc.ymd(2014, 4, 8).and_hms(0, 0, 0),
+            ),
+            (
+                "2014年04月08日星期二 11时25分18秒",
+                Utc.ymd(2014, 4, 8).and_hms(11, 25, 18),
+            ),
+            (
+                "2014年04月13日 星期日",
+                Utc.ymd(2014, 4, 13).and_hms(0, 0, 0),
+            ),
+        ];
+
+        for &(input, want) in test_cases.iter() {
+            assert_eq!(
+                parse.parse(input).unwrap(),
+                want,
+                "chinese_weekday/{}",
+                input
+            )
+        }
+    }
+
+    #[test]
+    fn day_of_month() {
+        let parse = Parse::new(&Utc, Some(NaiveTime::from_hms(0, 0, 0)));
+
+        let test_cases = [
+            ("3rd of July 2013", Utc.ymd(2013, 7, 3).and_hms(0, 0, 0)),
+            (
+                "the 3rd of July, 2013",
+                Utc.ymd(2013, 7, 3).and_hms(0, 0, 0),
+            ),
+            ("The 1st of May 2021", Utc.ymd(2021, 5, 1).and_hms(0, 0, 0)),
+            (
+                "22nd of February 2013",
+                Utc.ymd(2013, 2, 22).and_hms(0, 0, 0),
+            ),
+            ("11th of Nov 2013", Utc.ymd(2013, 11, 11).and_hms(0, 0, 0)),
+            ("3 of July 2013", Utc.ymd(2013, 7, 3).and_hms(0, 0, 0)),
+            ("3rd July 2013", Utc.ymd(2013, 7, 3).and_hms(0, 0, 0)),
+            (
+                "the 3rd of July 2013 10:30",
+                Utc.ymd(2013, 7, 3).and_hms(10, 30, 0),
+            ),
+            (
+                "3rd of July 2013 at 10:30",
+                Utc.ymd(2013, 7, 3).and_hms(10, 30, 0),
+            ),
+        ];
+
+        for &(input, want) in test_cases.iter() {
+            assert_eq!(parse.parse(input).unwrap(), want, "day_of_month/{}", input)
+        }
+
+        // `of` is only dropped between a day and a month name
+        assert!(parse.parse("3rd of the month 2013").is_err());
+        assert!(parse.parse("the 3rd of 2013").is_err());
+    }
+
+    #[test]
+    fn portuguese_date() {
+        let parse = Parse::new(&Utc, Some(NaiveTime::from_hms(0, 0, 0)));
+
+        let test_cases = [
+            ("8 de maio de 2009", Utc.ymd(2009, 5, 8).and_hms(0, 0, 0)),
+            (
+                "8 de maio de 2009 17:57",
+                Utc.ymd(2009, 5, 8).and_hms(17, 57, 0),
+            ),
+            (
+                "25 de dezembro de 2020 08:30:15",
+                Utc.ymd(2020, 12, 25).and_hms(8, 30, 15),
+            ),
+            ("1 de Março de 2021", Utc.ymd(2021, 3, 1).and_hms(0, 0, 0)),
+            (
+                "01 DE JANEIRO DE 2021",
+                Utc.ymd(2021, 1, 1).and_hms(0, 0, 0),
+            ),
+        ];
+
+        for &(input, want) in test_cases.iter() {
+            assert_eq!(
+                parse.parse(input).unwrap(),
+                want,
+                "portuguese_date/{}",
+                input
+            )
+        }
+        assert!(parse.parse("8 de maios de 2009").is_err());
+        assert!(parse.parse("31 de fevereiro de 2021").is_err());
+    }
+
+    #[test]
+    fn leading_meridiem() {
+        let parse = Parse::new(&Utc, None);
+
+        let test_cases = [
+            ("PM 5:57", Utc::now().date().and_hms(17, 57, 0)),
+            ("pm 5:57:30", Utc::now().date().and_hms(17, 57, 30)),
+            ("AM 12:15", Utc::now().date().and_hms(0, 15, 0)),
+            ("AM 9:05 UTC", Utc::now().date().and_hms(9, 5, 0)),
+        ];
+
+        for &(input, want) in test_cases.iter() {
+            assert_eq!(
+                parse.parse(input).unwrap(),
+                want,
+                "leading_meridiem/{}",
+                input
+            )
+        }
+
+        assert!(parse.parse("PM 25:57").is_err());
+        assert!(parse.parse("PM").is_err());
+    }
+
+    #[test]
+    fn invalid_date() {
+        let parse = Parse::new(&Utc, None);
+
+        let test_cases = [
+            ("2021-02-30", "2021-02-30"),
+            ("2021-13-01", "2021-13-01"),
+            ("2021-02-30 PST", "2021-02-30"),
+            ("2021-02-30 10:00:00", "2021-02-30"),
+            ("2021-02-30T10:00:00", "2021-02-30"),
+            ("2021-13-01 10:00:00 UTC", "2021-13-01"),
+        ];
+
+        for &(input, date) in test_cases.iter() {
+            let err = parse.parse(input).unwrap_err();
+            assert_eq!(
+                err.downcast_ref::<ParseError>(),
+                Some(&ParseError::InvalidDate(date.to_string())),
+                "invalid_date/{}",
+                input
+            );
+            assert_eq!(err.to_string(), format!("{} is not a valid date.", date));
+        }
+        assert!(parse
+            .parse("2021-02-28 25:00:00")
+            .unwrap_err()
+            .downcast_ref::<ParseError>()
+            .is_none());
+    }
+
+    #[test]
+    fn invalid_time() {
+        let parse = Parse::new(&Utc, None);
+
+        for input in ["25:00", "24:00", "12:60", "12:30:61", "25:00 pm"] {
+            let err = parse.parse(input).unwrap_err();
+            assert_eq!(
+                err.downcast_ref::<ParseError>(),
+                Some(&ParseError::InvalidTime(input.to_string())),
+                "invalid_time/{}",
+                input
+            );
+            assert_eq!(err.to_string(), format!("{} is not a valid time.", input));
+        }
+        // `hh:mm` is never read as minutes and seconds
+        assert_eq!(
+            parse.parse("01:06").unwrap(),
+            Utc::now().date().and_hms(1, 6, 0)
+        );
+    }
+
+    #[test]
+    fn leap_second() {
+        let parse = Parse::new(&Utc, None);
+        let want = NaiveDate::from_ymd(2016, 12, 31).and_hms_nano(23, 59, 59, 1_000_000_000);
+
+        let test_cases = [
+            "2016-12-31 23:59:60 UTC",
+            "2016-12-31 23:59:60",
+            "2016-12-31T23:59:60Z",
+            "Sat, 31 Dec 2016 23:59:60 GMT",
+            "12/31/2016 23:59:60",
+            "Dec 31, 2016 23:59:60",
+        ];
+        for &input in test_cases.iter() {
+            let parsed = parse.parse(input).unwrap();
+            assert_eq!(parsed.naive_utc(), want, "leap_second/{}", input);
+            assert_eq!(parsed.timestamp(), 1483228799, "leap_second/{}", input);
+        }
+
+        let parsed = parse.parse("23:59:60").unwrap();
+        assert_eq!(
+            parsed.time(),
+            NaiveTime::from_hms_nano(23, 59, 59, 1_000_000_000)
+        );
+    }
+
+    #[test]
+    fn nonexistent_local_time() {
+        // 2021-03-14 02:00 EST jumps to 03:00 EDT in New York
+        let parse = Parse::new(&New_York, None);
+        let gap = NaiveDate::from_ymd(2021, 3, 14).and_hms(2, 30, 0);
+
+        for input in [
+            "2021-03-14 02:30:00",
+            "2021-03-14 02:30",
+            "03/14/2021 02:30",
+        ] {
+            let err = parse.parse(input).unwrap_err();
+            assert_eq!(
+                err.downcast_ref::<ParseError>(),
+                Some(&ParseError::NonexistentLocalTime(gap)),
+                "nonexistent_local_time/{}",
+                input
+            );
+        }
+        assert_eq!(
+            parse.parse("2021-03-14 03:30:00").unwrap(),
+            Utc.ymd(2021, 3, 14).and_hms(7, 30, 0)
+        );
+    }
+
+    #[test]
+    fn ambiguous_local_time() {
+        // 2021-11-07 02:00 EDT falls back to 01:00 EST in New York
+        let input = "2021-11-07 01:30:00";
+
+        let parse = Parse::new(&New_York, None);
+        assert_eq!(
+            parse.parse(input).unwrap(),
+            Utc.ymd(2021, 11, 7).and_hms(5, 30, 0),
+            "ambiguous_local_time/default"
+        );
+
+        let parse = Parse::new(&New_York, None).dst_policy(DstPolicy::Earliest);
+        assert_eq!(
+            parse.parse(input).unwrap(),
+            Utc.ymd(2021, 11, 7).and_hms(5, 30, 0),
+            "ambiguous_local_time/earliest"
+        );
+
+        let parse = Parse::new(&New_York, None).dst_policy(DstPolicy::Latest);
+        assert_eq!(
+            parse.parse(input).unwrap(),
+            Utc.ymd(2021, 11, 7).and_hms(6, 30, 0),
+            "ambiguous_local_time/latest"
+        );
+
+        let parse = Parse::new(&New_York, None).dst_policy(DstPolicy::Error);
+        let err = parse.parse(input).unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<ParseError>(),
+            Some(&ParseError::AmbiguousLocalTime(
+                NaiveDate::from_ymd(2021, 11, 7).and_hms(1, 30, 0)
+            )),
+            "ambiguous_local_time/error"
+        );
+    }
+
+    #[test]
+    fn owned_parse() {
+        let parse = OwnedParse::new_owned(New_York, None).dst_policy(DstPolicy::Latest);
+        let cloned = parse.clone();
+        drop(parse);
+
+        assert_eq!(
+            cloned.parse("2021-04-30 21:14:10").unwrap(),
+            Utc.ymd(2021, 5, 1).and_hms(1, 14, 10)
+        );
+        assert_eq!(
+            cloned.parse("2021-11-07 01:30:00").unwrap(),
+            Utc.ymd(2021, 11, 7).and_hms(6, 30, 0)
+        );
+    }
+
+    #[test]
+    fn parse_all() {
+        let parse = Parse::new(&Utc, Some(NaiveTime::from_hms(0, 0, 0)));
+
+        assert_eq!(
+            parse.parse_all("04/05/06"),
+            vec![(Utc.ymd(2006, 4, 5).and_hms(0, 0, 0), "slash_mdy")]
+        );
+        assert_eq!(
+            parse.parse_all("2021-04-30 21:14:10"),
+            vec![(Utc.ymd(2021, 4, 30).and_hms(21, 14, 10), "ymd_hms")]
+        );
+        assert_eq!(
+            parse.parse_all("2017-11-25T22:34:50Z"),
+            vec![(Utc.ymd(2017, 11, 25).and_hms(22, 34, 50), "rfc3339")]
+        );
+        assert!(parse.parse_all("0000-00-00").is_empty());
+        assert!(parse.parse_all("not-date-time").is_empty());
+    }
+
+    #[test]
+    fn parse_elapsed() {
+        let parse = Parse::new(&Utc, None);
+
+        let test_cases = [
+            ("01:06:06", chrono::Duration::seconds(3966)),
+            ("25:30:00", chrono::Duration::minutes(25 * 60 + 30)),
+            ("100:00:00", chrono::Duration::hours(100)),
+            ("0:00:59", chrono::Duration::seconds(59)),
+        ];
+
+        for &(input, want) in test_cases.iter() {
+            assert_eq!(
+                parse.parse_elapsed(input).unwrap(),
+                want,
+                "parse_elapsed/{}",
+                input
+            )
+        }
+        assert!(parse.parse_elapsed("25:60:00").is_err());
+        assert!(parse.parse_elapsed("25:30").is_err());
+        assert!(parse.parse_elapsed("99999999999999999999:00:00").is_err());
+        assert!(parse.parse_elapsed("not-elapsed-time").is_err());
+    }
+
+    #[test]
+    fn parse_range() {
+        let parse = Parse::new(&Utc, Some(NaiveTime::from_hms(0, 0, 0)));
+
+        let test_cases = [
+            (
+                "2021-01-01 to 2021-01-31",
+                Utc.ymd(2021, 1, 1).and_hms(0, 0, 0),
+                Utc.ymd(2021, 1, 31).and_hms(0, 0, 0),
+            ),
+            (
+                "2021-01-01 - 2021-01-31",
+                Utc.ymd(2021, 1, 1).and_hms(0, 0, 0),
+                Utc.ymd(2021, 1, 31).and_hms(0, 0, 0),
+            ),
+            (
+                "2021-01-01..2021-01-31",
+                Utc.ymd(2021, 1, 1).and_hms(0, 0, 0),
+                Utc.ymd(2021, 1, 31).and_hms(0, 0, 0),
+            ),
+            (
+                "2021-01-01 — 2021-01-31",
+                Utc.ymd(2021, 1, 1).and_hms(0, 0, 0),
+                Utc.ymd(2021, 1, 31).and_hms(0, 0, 0),
+            ),
+            (
+                "May 8, 2009 5:57:51 PM to 2021-04-30 21:14:10",
+                Utc.ymd(2009, 5, 8).and_hms(17, 57, 51),
+                Utc.ymd(2021, 4, 30).and_hms(21, 14, 10),
+            ),
+        ];
+
+        for &(input, start, end) in test_cases.iter() {
+            assert_eq!(
+                parse.parse_range(input).unwrap(),
+                (start, end),
+                "parse_range/{}",
+                input
+            )
+        }
+        assert!(parse.parse_range("2021-01-31 to 2021-01-01").is_err());
+        assert!(parse.parse_range("2021-01-01").is_err());
+        assert!(parse.parse_range("2021-01-01-2021-01-31").is_err());
+        assert!(parse.parse_range("2021-01-01 to not-date-time").is_err());
     }
 }
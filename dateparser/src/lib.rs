@@ -60,15 +60,39 @@
 //! use dateparser::DateTimeUtc;
 //!
 //! let accepted = vec![
-//!     // unix timestamp
+//!     // unix timestamp, in seconds, milliseconds, microseconds or nanoseconds by the number of
+//!     // digits, or for any other number of digits, in the closest unit that gives a year from
+//!     // 1970 to 2100
 //!     "1511648546",
 //!     "1620021848429",
+//!     "1620024872717915",
 //!     "1620024872717915000",
+//!     // unix timestamp in seconds after an @, as GNU date takes it, and the epoch itself
+//!     "@1511648546",
+//!     "epoch",
+//!     // unix timestamp with underscores between the digits for readability
+//!     "1_511_648_546",
+//!     // unix timestamp with a unit
+//!     "1620021848s",
+//!     "1620021848429ms",
+//!     "1620024872717915us",
+//!     "1620024872717915000ns",
 //!     // rfc3339
 //!     "2021-05-01T01:17:02.604456Z",
 //!     "2017-11-25T22:34:50Z",
+//!     "2021-05-01t01:17:02z",
 //!     // rfc2822
 //!     "Wed, 02 Jun 2021 06:31:39 GMT",
+//!     // cookie date
+//!     "Wed, 09-Jun-2021 10:18:14 GMT",
+//!     "Wed, 09-Jun-21 10:18:14 GMT",
+//!     // rfc850
+//!     "Monday, 02-Jan-06 15:04:05 GMT",
+//!     // ansi c asctime
+//!     "Wed Jun  2 06:31:39 2021",
+//!     // unix date command output
+//!     "Wed Jun  2 06:31:39 UTC 2021",
+//!     "Wed Jun  2 06:31:39 PDT 2021",
 //!     // postgres timestamp yyyy-mm-dd hh:mm:ss z
 //!     "2019-11-29 08:08-08",
 //!     "2019-11-29 08:08:05-08",
@@ -76,6 +100,9 @@
 //!     "2021-05-02 23:31:39.12689-07",
 //!     "2019-11-29 08:15:47.624504-08",
 //!     "2017-07-19 03:21:51+00:00",
+//!     // go time.Time.String()
+//!     "2021-05-01 10:00:00.123456789 +0000 UTC",
+//!     "2021-05-01 18:00:00 +0800 CST",
 //!     // yyyy-mm-dd hh:mm:ss
 //!     "2014-04-26 05:24:37 PM",
 //!     "2021-04-30 21:14",
@@ -92,36 +119,81 @@
 //!     "2014-04-26 13:13:43 +0800",
 //!     "2014-04-26 13:13:44 +09:00",
 //!     "2012-08-03 18:31:59.257000000 +0000",
+//!     "2012-08-03 18:31:59 +0000UTC",
 //!     "2015-09-30 18:48:56.35272715 UTC",
+//!     "2014-04-26 05:24:37 PM PST",
+//!     "2021-05-01 10:00:00 GMT+8",
+//!     "2021-05-01 10:00:00 UTC-05:00",
+//!     "2017-11-25 13:31:15 PST (Pacific Standard Time)",
+//!     "2021-05-14T18:51:00 GMT",
+//!     "2021-05-14T18:51 PST",
 //!     // yyyy-mm-dd
 //!     "2021-02-21",
 //!     // yyyy-mm-dd z
 //!     "2021-02-21 PST",
 //!     "2021-02-21 UTC",
 //!     "2020-07-20+08:00",
+//!     "2020-07-20 +08",
+//!     // yy-mm-dd
+//!     "21-02-21",
+//!     // basic iso 8601 yyyymmdd and yyyymmddThhmmss
+//!     "20210501",
+//!     "20210501T101112",
+//!     "20210501T101112Z",
+//!     "20210501T101112+0800",
 //!     // hh:mm:ss
 //!     "01:06:06",
 //!     "4:00pm",
+//!     "3:04PM",
 //!     "6:00 AM",
 //!     // hh:mm:ss z
 //!     "01:06:06 PST",
 //!     "4:00pm PST",
 //!     "6:00 AM PST",
 //!     "6:00pm UTC",
+//!     // hh am/pm
+//!     "11AM",
+//!     "3pm",
+//!     "7 AM",
 //!     // Mon dd hh:mm:ss
 //!     "May 6 at 9:24 PM",
 //!     "May 27 02:45:27",
 //!     // Mon dd, yyyy, hh:mm:ss
 //!     "May 8, 2009 5:57:51 PM",
 //!     "September 17, 2012 10:09am",
+//!     "September 17, 2012 at 10:09am",
 //!     "September 17, 2012, 10:10:09",
 //!     // Mon dd, yyyy hh:mm:ss z
 //!     "May 02, 2021 15:51:31 UTC",
 //!     "May 02, 2021 15:51 UTC",
+//!     "May 02, 2021 15:51:31.123456 UTC",
 //!     "May 26, 2021, 12:49 AM PDT",
 //!     "September 17, 2012 at 10:09am PST",
+//!     "Wed Jun 02 2021 06:31:39 GMT-0700 (Pacific Daylight Time)",
 //!     // yyyy-mon-dd
 //!     "2021-Feb-21",
+//!     // quarter
+//!     "Q2 2021",
+//!     "2021-Q2",
+//!     // year month
+//!     "2021-05",
+//!     "2021/05",
+//!     "May 2021",
+//!     "2021-May",
+//!     // week of year
+//!     "Week 23 of 2021",
+//!     "2021 week 23",
+//!     // relative weekday
+//!     "next Friday",
+//!     "last mon",
+//!     "this week",
+//!     "this weekend",
+//!     // iso 8601 duration from now, with weeks, days, hours, minutes and seconds
+//!     "P1D",
+//!     "-PT2H",
+//!     // business day shorthands: EOD, BOD and COB
+//!     "EOD",
+//!     "2021-05-01 COB",
 //!     // Mon dd, yyyy
 //!     "May 25, 2021",
 //!     "oct 7, 1970",
@@ -138,6 +210,8 @@
 //!     "7 oct 1970",
 //!     "03 February 2013",
 //!     "1 July 2013",
+//!     "the 3rd of July, 2013",
+//!     "8 de maio de 2009",
 //!     // mm/dd/yyyy hh:mm:ss
 //!     "4/8/2014 22:05",
 //!     "04/08/2014 22:05",
@@ -177,8 +251,18 @@
 //!     "171113 14:14:20",
 //!     // chinese yyyy mm dd hh mm ss
 //!     "2014年04月08日11时25分18秒",
+//!     "2014年04月08日11时25分18.5秒",
+//!     // chinese yyyy mm dd 上午/下午 hh mm ss
+//!     "2014年04月08日上午11时25分18秒",
+//!     "2014年04月08日 下午3时25分",
+//!     "2014年04月08日 星期二 下午3时",
 //!     // chinese yyyy mm dd
 //!     "2014年04月08日",
+//!     "2014年 4月 8日",
+//!     // korean yyyy mm dd hh mm ss
+//!     "2014년04월08일 11시25분18초",
+//!     // korean yyyy mm dd
+//!     "2014년04월08일",
 //! ];
 //!
 //! for date_str in accepted {
@@ -235,11 +319,16 @@ pub mod timezone;
 use crate::datetime::Parse;
 use anyhow::{Error, Result};
 use chrono::prelude::*;
+use std::ops::Range;
 
 /// DateTimeUtc is an alias for `chrono`'s `DateTime<UTC>`. It implements `std::str::FromStr`'s
 /// `from_str` method, and it makes `str`'s `parse` method to understand the accepted date formats
 /// from this crate.
 ///
+/// The error of `from_str` always shows the input it failed on, so that it is still clear which
+/// input was wrong when the error is reported away from it, e.g. by serde or clap. A
+/// [`datetime::ParseError`] is kept and can be told apart with `downcast_ref`.
+///
 /// ```
 /// use dateparser::DateTimeUtc;
 ///
@@ -256,7 +345,30 @@ impl std::str::FromStr for DateTimeUtc {
     type Err = Error;
 
     fn from_str(s: &str) -> Result<Self> {
-        parse(s).map(DateTimeUtc)
+        parse(s).map(DateTimeUtc).map_err(|err| {
+            if err.to_string().contains(s) {
+                err
+            } else {
+                let message = format!("{} could not be parsed: {}", s, err);
+                err.context(message)
+            }
+        })
+    }
+}
+
+impl TryFrom<&str> for DateTimeUtc {
+    type Error = Error;
+
+    fn try_from(s: &str) -> Result<Self> {
+        s.parse()
+    }
+}
+
+impl TryFrom<String> for DateTimeUtc {
+    type Error = Error;
+
+    fn try_from(s: String) -> Result<Self> {
+        s.parse()
     }
 }
 
@@ -289,6 +401,8 @@ pub fn parse(input: &str) -> Result<DateTime<Utc>> {
 /// Similar to [`parse()`], this function takes a datetime string and a custom [`chrono::TimeZone`],
 /// and tries to parse the datetime string. When timezone is not given in the string, this function
 /// will assume and parse the datetime by the custom timezone provided in this function's arguments.
+/// When such a datetime occurs twice in the custom timezone, e.g. during the hour repeated when
+/// daylight saving time ends, the earlier one is used. See [`datetime::DstPolicy`].
 ///
 /// ```
 /// use dateparser::parse_with_timezone;
@@ -313,10 +427,40 @@ pub fn parse(input: &str) -> Result<DateTime<Utc>> {
 ///     Utc::now().with_timezone(&Pacific).date().and_hms(18, 15, 0).with_timezone(&Utc),
 /// );
 /// ```
+///
+/// A timezone given in the datetime string always wins over the custom timezone, which only fills
+/// in when the string has none:
+///
+/// ```
+/// use dateparser::parse_with_timezone;
+/// use chrono::prelude::*;
+///
+/// let parsed = parse_with_timezone("2021-05-01 10:00 PST", &Utc).unwrap();
+/// assert_eq!(parsed, Utc.ymd(2021, 5, 1).and_hms(18, 0, 0));
+/// ```
 pub fn parse_with_timezone<Tz2: TimeZone>(input: &str, tz: &Tz2) -> Result<DateTime<Utc>> {
     Parse::new(tz, None).parse(input)
 }
 
+/// Similar to [`parse_with_timezone()`], but returns the parsed datetime in the custom timezone
+/// instead of UTC. A datetime string carrying its own timezone is parsed as that instant, which is
+/// then shown in the custom timezone.
+///
+/// ```
+/// use dateparser::parse_in_tz;
+/// use chrono::prelude::*;
+/// use chrono_tz::Asia::Tokyo;
+///
+/// let parsed = parse_in_tz("2021-05-01 10:00", &Tokyo).unwrap();
+/// assert_eq!(parsed, Tokyo.ymd(2021, 5, 1).and_hms(10, 0, 0));
+///
+/// let parsed = parse_in_tz("2021-05-01 10:00 UTC", &Tokyo).unwrap();
+/// assert_eq!(parsed, Tokyo.ymd(2021, 5, 1).and_hms(19, 0, 0));
+/// ```
+pub fn parse_in_tz<Tz2: TimeZone>(input: &str, tz: &Tz2) -> Result<DateTime<Tz2>> {
+    parse_with_timezone(input, tz).map(|parsed| parsed.with_timezone(tz))
+}
+
 /// Similar to [`parse()`] and [`parse_with_timezone()`], this function takes a datetime string, a
 /// custom [`chrono::TimeZone`] and a default naive time. In addition to assuming timezone when
 /// it's not given in datetime string, this function also use provided default naive time in parsed
@@ -371,9 +515,90 @@ pub fn parse_with<Tz2: TimeZone>(
     Parse::new(tz, Some(default_time)).parse(input)
 }
 
+/// Similar to [`parse()`], but this function returns `None` instead of an error when the datetime
+/// string cannot be parsed.
+///
+/// ```
+/// use dateparser::try_parse;
+/// use chrono::prelude::*;
+///
+/// assert_eq!(
+///     try_parse("2021-05-01 10:00:00 UTC"),
+///     Some(Utc.ymd(2021, 5, 1).and_hms(10, 0, 0)),
+/// );
+/// assert_eq!(try_parse("not a date"), None);
+/// ```
+pub fn try_parse(input: &str) -> Option<DateTime<Utc>> {
+    Parse::new(&Local, None).try_parse(input)
+}
+
+/// This function tells whether a string is written in one of the accepted formats, see
+/// [`datetime::Parse::is_date`].
+///
+/// ```
+/// use dateparser::is_date;
+///
+/// assert!(is_date("2021-05-01 10:00:00 UTC"));
+/// assert!(!is_date("not a date"));
+/// ```
+pub fn is_date(input: &str) -> bool {
+    Parse::new(&Local, None).is_date(input)
+}
+
+/// Similar to [`parse()`], this function takes a string with two datetimes, and parses it into a
+/// range of start and end datetimes. Start and end can be separated by ` to `, a spaced dash ` - `,
+/// `..` or `—`.
+///
+/// ```
+/// use dateparser::parse_range;
+/// use chrono::prelude::*;
+///
+/// let (start, end) = parse_range("2021-01-01 00:00:00 UTC to 2021-01-31 23:59:59 UTC").unwrap();
+///
+/// assert_eq!(start, Utc.ymd(2021, 1, 1).and_hms(0, 0, 0));
+/// assert_eq!(end, Utc.ymd(2021, 1, 31).and_hms(23, 59, 59));
+/// ```
+pub fn parse_range(input: &str) -> Result<(DateTime<Utc>, DateTime<Utc>)> {
+    Parse::new(&Local, None).parse_range(input)
+}
+
+/// Similar to [`parse()`], but this function finds the first datetime inside of a longer text, such
+/// as a log line or a message, and returns it along with the byte range it takes up in the text.
+///
+/// ```
+/// use dateparser::parse_first_in_text;
+/// use chrono::prelude::*;
+///
+/// let text = "Backup completed at 2021-05-01 10:00:00 UTC successfully";
+/// let (parsed, range) = parse_first_in_text(text).unwrap();
+///
+/// assert_eq!(parsed, Utc.ymd(2021, 5, 1).and_hms(10, 0, 0));
+/// assert_eq!(range, 20..43);
+/// ```
+pub fn parse_first_in_text(input: &str) -> Result<(DateTime<Utc>, Range<usize>)> {
+    Parse::new(&Local, None).parse_first_in_text(input)
+}
+
+/// Similar to [`parse()`], but this function parses a datetime from the start of a string, and
+/// returns it along with the rest of the string after it.
+///
+/// ```
+/// use dateparser::parse_prefix;
+/// use chrono::prelude::*;
+///
+/// let (parsed, rest) = parse_prefix("2021-05-01 10:00:00 UTC backup completed").unwrap();
+///
+/// assert_eq!(parsed, Utc.ymd(2021, 5, 1).and_hms(10, 0, 0));
+/// assert_eq!(rest, " backup completed");
+/// ```
+pub fn parse_prefix(input: &str) -> Result<(DateTime<Utc>, &str)> {
+    Parse::new(&Local, None).parse_prefix(input)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use chrono_tz::America::New_York;
 
     #[derive(Clone, Copy)]
     enum Trunc {
@@ -402,6 +627,36 @@ mod tests {
                 Utc.ymd(2021, 6, 2).and_hms(6, 31, 39),
                 Trunc::None,
             ),
+            (
+                "cookie_date",
+                "Wed, 09-Jun-2021 10:18:14 GMT",
+                Utc.ymd(2021, 6, 9).and_hms(10, 18, 14),
+                Trunc::None,
+            ),
+            (
+                "rfc850",
+                "Monday, 02-Jan-06 15:04:05 GMT",
+                Utc.ymd(2006, 1, 2).and_hms(15, 4, 5),
+                Trunc::None,
+            ),
+            (
+                "ansi_c",
+                "Wed Jun  2 06:31:39 2021",
+                Local.ymd(2021, 6, 2).and_hms(6, 31, 39).with_timezone(&Utc),
+                Trunc::None,
+            ),
+            (
+                "unix_date",
+                "Wed Jun  2 06:31:39 PDT 2021",
+                Utc.ymd(2021, 6, 2).and_hms(13, 31, 39),
+                Trunc::None,
+            ),
+            (
+                "js_date_string",
+                "Wed Jun 02 2021 06:31:39 GMT-0700 (Pacific Daylight Time)",
+                Utc.ymd(2021, 6, 2).and_hms(13, 31, 39),
+                Trunc::None,
+            ),
             (
                 "postgres_timestamp",
                 "2019-11-29 08:08:05-08",
@@ -423,6 +678,12 @@ mod tests {
                 Utc.ymd(2017, 11, 25).and_hms(21, 31, 15),
                 Trunc::None,
             ),
+            (
+                "ymd_hms_z",
+                "2021-05-14T18:51:00 GMT",
+                Utc.ymd(2021, 5, 14).and_hms(18, 51, 0),
+                Trunc::None,
+            ),
             (
                 "ymd",
                 "2021-02-21",
@@ -468,6 +729,16 @@ mod tests {
                     .with_timezone(&Utc),
                 Trunc::None,
             ),
+            (
+                "hour_meridiem",
+                "3pm",
+                Local::now()
+                    .date()
+                    .and_time(NaiveTime::from_hms(15, 0, 0))
+                    .unwrap()
+                    .with_timezone(&Utc),
+                Trunc::None,
+            ),
             (
                 "month_ymd",
                 "2021-Feb-21",
@@ -478,6 +749,36 @@ mod tests {
                     .with_timezone(&Utc),
                 Trunc::Seconds,
             ),
+            (
+                "quarter",
+                "Q2 2021",
+                Local
+                    .ymd(2021, 4, 1)
+                    .and_time(Local::now().time())
+                    .unwrap()
+                    .with_timezone(&Utc),
+                Trunc::Seconds,
+            ),
+            (
+                "year_month",
+                "May 2021",
+                Local
+                    .ymd(2021, 5, 1)
+                    .and_time(Local::now().time())
+                    .unwrap()
+                    .with_timezone(&Utc),
+                Trunc::Seconds,
+            ),
+            (
+                "week_of_year",
+                "Week 23 of 2021",
+                Local
+                    .ymd(2021, 6, 7)
+                    .and_time(Local::now().time())
+                    .unwrap()
+                    .with_timezone(&Utc),
+                Trunc::Seconds,
+            ),
             (
                 "month_md_hms",
                 "May 27 02:45:27",
@@ -607,6 +908,25 @@ mod tests {
                     .with_timezone(&Utc),
                 Trunc::Seconds,
             ),
+            (
+                "korean_ymd_hms",
+                "2014년04월08일 11시25분18초",
+                Local
+                    .ymd(2014, 4, 8)
+                    .and_hms(11, 25, 18)
+                    .with_timezone(&Utc),
+                Trunc::None,
+            ),
+            (
+                "korean_ymd",
+                "2014년04월08일",
+                Local
+                    .ymd(2014, 4, 8)
+                    .and_time(Local::now().time())
+                    .unwrap()
+                    .with_timezone(&Utc),
+                Trunc::Seconds,
+            ),
         ];
 
         for &(test, input, want, trunc) in test_cases.iter() {
@@ -656,6 +976,36 @@ mod tests {
                 Utc.ymd(2021, 6, 2).and_hms(6, 31, 39),
                 Trunc::None,
             ),
+            (
+                "cookie_date",
+                "Wed, 09-Jun-2021 10:18:14 GMT",
+                Utc.ymd(2021, 6, 9).and_hms(10, 18, 14),
+                Trunc::None,
+            ),
+            (
+                "rfc850",
+                "Monday, 02-Jan-06 15:04:05 GMT",
+                Utc.ymd(2006, 1, 2).and_hms(15, 4, 5),
+                Trunc::None,
+            ),
+            (
+                "ansi_c",
+                "Wed Jun  2 06:31:39 2021",
+                Utc.ymd(2021, 6, 2).and_hms(6, 31, 39),
+                Trunc::None,
+            ),
+            (
+                "unix_date",
+                "Wed Jun  2 06:31:39 PDT 2021",
+                Utc.ymd(2021, 6, 2).and_hms(13, 31, 39),
+                Trunc::None,
+            ),
+            (
+                "js_date_string",
+                "Wed Jun 02 2021 06:31:39 GMT-0700 (Pacific Daylight Time)",
+                Utc.ymd(2021, 6, 2).and_hms(13, 31, 39),
+                Trunc::None,
+            ),
             (
                 "postgres_timestamp",
                 "2019-11-29 08:08:05-08",
@@ -674,6 +1024,12 @@ mod tests {
                 Utc.ymd(2017, 11, 25).and_hms(21, 31, 15),
                 Trunc::None,
             ),
+            (
+                "ymd_hms_z",
+                "2021-05-14T18:51:00 GMT",
+                Utc.ymd(2021, 5, 14).and_hms(18, 51, 0),
+                Trunc::None,
+            ),
             (
                 "ymd",
                 "2021-02-21",
@@ -718,12 +1074,39 @@ mod tests {
                     .with_timezone(&Utc),
                 Trunc::None,
             ),
+            (
+                "hour_meridiem",
+                "3pm",
+                Utc::now()
+                    .date()
+                    .and_time(NaiveTime::from_hms(15, 0, 0))
+                    .unwrap(),
+                Trunc::None,
+            ),
             (
                 "month_ymd",
                 "2021-Feb-21",
                 Utc.ymd(2021, 2, 21).and_time(Utc::now().time()).unwrap(),
                 Trunc::Seconds,
             ),
+            (
+                "quarter",
+                "2021-Q2",
+                Utc.ymd(2021, 4, 1).and_time(Utc::now().time()).unwrap(),
+                Trunc::Seconds,
+            ),
+            (
+                "year_month",
+                "2021-05",
+                Utc.ymd(2021, 5, 1).and_time(Utc::now().time()).unwrap(),
+                Trunc::Seconds,
+            ),
+            (
+                "week_of_year",
+                "2021 week 23",
+                Utc.ymd(2021, 6, 7).and_time(Utc::now().time()).unwrap(),
+                Trunc::Seconds,
+            ),
             (
                 "month_md_hms",
                 "May 27 02:45:27",
@@ -808,6 +1191,18 @@ mod tests {
                 Utc.ymd(2014, 4, 8).and_time(Utc::now().time()).unwrap(),
                 Trunc::Seconds,
             ),
+            (
+                "korean_ymd_hms",
+                "2014년04월08일 11시25분18초",
+                Utc.ymd(2014, 4, 8).and_hms(11, 25, 18),
+                Trunc::None,
+            ),
+            (
+                "korean_ymd",
+                "2014년04월08일",
+                Utc.ymd(2014, 4, 8).and_time(Utc::now().time()).unwrap(),
+                Trunc::Seconds,
+            ),
         ];
 
         for &(test, input, want, trunc) in test_cases.iter() {
@@ -836,6 +1231,151 @@ mod tests {
         }
     }
 
+    #[test]
+    fn parse_with_timezone_explicit_zone() {
+        let test_cases = [
+            (
+                "2021-05-01 10:00 PST",
+                Utc.ymd(2021, 5, 1).and_hms(18, 0, 0),
+            ),
+            (
+                "2021-05-01 10:00:00 +09:00",
+                Utc.ymd(2021, 5, 1).and_hms(1, 0, 0),
+            ),
+            (
+                "2021-05-01T10:00:00-04:00",
+                Utc.ymd(2021, 5, 1).and_hms(14, 0, 0),
+            ),
+            (
+                "Wed, 02 Jun 2021 06:31:39 PDT",
+                Utc.ymd(2021, 6, 2).and_hms(13, 31, 39),
+            ),
+            (
+                "May 02, 2021 15:51 UTC",
+                Utc.ymd(2021, 5, 2).and_hms(15, 51, 0),
+            ),
+            (
+                "2021-05-02 23:31:39.12689-07",
+                Utc.ymd(2021, 5, 3).and_hms_micro(6, 31, 39, 126890),
+            ),
+        ];
+        let east = FixedOffset::east(9 * 3600);
+        let west = FixedOffset::west(5 * 3600);
+
+        for &(input, want) in test_cases.iter() {
+            assert_eq!(
+                super::parse_with_timezone(input, &Utc).unwrap(),
+                want,
+                "parse_with_timezone_explicit_zone/utc/{}",
+                input
+            );
+            assert_eq!(
+                super::parse_with_timezone(input, &east).unwrap(),
+                want,
+                "parse_with_timezone_explicit_zone/east/{}",
+                input
+            );
+            assert_eq!(
+                super::parse_with_timezone(input, &west).unwrap(),
+                want,
+                "parse_with_timezone_explicit_zone/west/{}",
+                input
+            );
+        }
+
+        // only the date of a time without one comes from today in its own zone
+        let now = Utc::now().with_timezone(&FixedOffset::west(8 * 3600));
+        let want = now.date().and_hms(16, 0, 0).with_timezone(&Utc);
+        for tz in [east, west] {
+            assert_eq!(
+                super::parse_with_timezone("4:00pm PST", &tz).unwrap(),
+                want,
+                "parse_with_timezone_explicit_zone/hms_z"
+            );
+        }
+    }
+
+    #[test]
+    fn parse_in_tz() {
+        let east = FixedOffset::east(9 * 3600);
+        let west = FixedOffset::west(5 * 3600);
+
+        let test_cases = [
+            (
+                "2021-05-01 10:00",
+                east.ymd(2021, 5, 1).and_hms(10, 0, 0),
+                west.ymd(2021, 5, 1).and_hms(10, 0, 0),
+            ),
+            (
+                "2021-05-01 10:00 PST",
+                east.ymd(2021, 5, 2).and_hms(3, 0, 0),
+                west.ymd(2021, 5, 1).and_hms(13, 0, 0),
+            ),
+            (
+                "2021-05-01T10:00:00-04:00",
+                east.ymd(2021, 5, 1).and_hms(23, 0, 0),
+                west.ymd(2021, 5, 1).and_hms(9, 0, 0),
+            ),
+            (
+                "Wed, 02 Jun 2021 06:31:39 PDT",
+                east.ymd(2021, 6, 2).and_hms(22, 31, 39),
+                west.ymd(2021, 6, 2).and_hms(8, 31, 39),
+            ),
+            (
+                "1511648546",
+                east.ymd(2017, 11, 26).and_hms(7, 22, 26),
+                west.ymd(2017, 11, 25).and_hms(17, 22, 26),
+            ),
+        ];
+
+        for &(input, want_east, want_west) in test_cases.iter() {
+            assert_eq!(
+                super::parse_in_tz(input, &east).unwrap(),
+                want_east,
+                "parse_in_tz/east/{}",
+                input
+            );
+            assert_eq!(
+                super::parse_in_tz(input, &west).unwrap(),
+                want_west,
+                "parse_in_tz/west/{}",
+                input
+            );
+        }
+
+        // a named zone follows its own daylight saving time
+        let test_cases = [
+            (
+                "2021-07-01 10:00",
+                New_York.ymd(2021, 7, 1).and_hms(10, 0, 0),
+                Utc.ymd(2021, 7, 1).and_hms(14, 0, 0),
+            ),
+            (
+                "2021-01-01 10:00",
+                New_York.ymd(2021, 1, 1).and_hms(10, 0, 0),
+                Utc.ymd(2021, 1, 1).and_hms(15, 0, 0),
+            ),
+            (
+                "2021-01-01 10:00 UTC",
+                New_York.ymd(2021, 1, 1).and_hms(5, 0, 0),
+                Utc.ymd(2021, 1, 1).and_hms(10, 0, 0),
+            ),
+            (
+                "2021-07-01 10:00 UTC",
+                New_York.ymd(2021, 7, 1).and_hms(6, 0, 0),
+                Utc.ymd(2021, 7, 1).and_hms(10, 0, 0),
+            ),
+        ];
+
+        for &(input, want, want_utc) in test_cases.iter() {
+            let parsed = super::parse_in_tz(input, &New_York).unwrap();
+            assert_eq!(parsed, want, "parse_in_tz/new_york/{}", input);
+            assert_eq!(parsed, want_utc, "parse_in_tz/new_york/utc/{}", input);
+        }
+
+        assert!(super::parse_in_tz("not a date", &east).is_err());
+    }
+
     // test parse_with() with various timezones and times
 
     #[test]
@@ -857,6 +1397,7 @@ mod tests {
             ("slash_ymd", "2023/4/21"),
             ("dot_mdy_or_ymd", "2023.04.21"),
             ("chinese_ymd", "2023年04月21日"),
+            ("korean_ymd", "2023년04월21일"),
         ];
 
         // test us_edt at midnight
@@ -900,6 +1441,7 @@ mod tests {
             ("slash_ymd", "2023/12/21"),
             ("dot_mdy_or_ymd", "2023.12.21"),
             ("chinese_ymd", "2023年12月21日"),
+            ("korean_ymd", "2023년12월21일"),
         ];
 
         // test us_est at midnight
@@ -938,6 +1480,7 @@ mod tests {
             ("slash_ymd", "2023/12/21"),
             ("dot_mdy_or_ymd", "2023.12.21"),
             ("chinese_ymd", "2023年12月21日"),
+            ("korean_ymd", "2023년12월21일"),
         ];
         // test utc at midnight
         let utc_midnight = Utc.ymd(2023, 12, 21).and_hms(0, 0, 0);
@@ -965,7 +1508,7 @@ mod tests {
     fn parse_with_local() {
         let midnight_naive = NaiveTime::from_hms_opt(0, 0, 0).unwrap();
         let before_midnight_naive = NaiveTime::from_hms_opt(23, 59, 59).unwrap();
-        let local_test_cases = vec![
+        let local_test_cases = [
             ("ymd", "2023-12-21"),
             ("month_ymd", "2023-Dec-21"),
             ("month_mdy", "December 21, 2023"),
@@ -974,6 +1517,7 @@ mod tests {
             ("slash_ymd", "2023/12/21"),
             ("dot_mdy_or_ymd", "2023.12.21"),
             ("chinese_ymd", "2023年12月21日"),
+            ("korean_ymd", "2023년12월21일"),
         ];
 
         // test local at midnight
@@ -1001,4 +1545,57 @@ mod tests {
             )
         }
     }
+
+    #[test]
+    fn try_from_str_and_string() {
+        let want = Utc.ymd(2021, 5, 2).and_hms(15, 51, 31);
+
+        let from_str = DateTimeUtc::try_from("May 02, 2021 15:51:31 UTC").unwrap();
+        assert_eq!(from_str.0, want);
+
+        let from_string = DateTimeUtc::try_from("May 02, 2021 15:51:31 UTC".to_string()).unwrap();
+        assert_eq!(from_string.0, want);
+
+        assert!(DateTimeUtc::try_from("not-date-time").is_err());
+        assert!(DateTimeUtc::try_from("not-date-time".to_string()).is_err());
+    }
+
+    #[test]
+    fn from_str_error_has_input() {
+        let err = "not-date-time".parse::<DateTimeUtc>().unwrap_err();
+        assert_eq!(err.to_string(), "not-date-time did not match any formats.");
+
+        let test_cases = [
+            (
+                "0000-00-00 00:00:00",
+                datetime::ParseError::ZeroDate,
+                "0000-00-00 00:00:00 could not be parsed: all-zeros date is not a valid date.",
+            ),
+            (
+                "2021-02-30 10:00:00",
+                datetime::ParseError::InvalidDate("2021-02-30".to_string()),
+                "2021-02-30 10:00:00 could not be parsed: 2021-02-30 is not a valid date.",
+            ),
+        ];
+        for (input, kind, message) in test_cases {
+            let err = input.parse::<DateTimeUtc>().unwrap_err();
+            assert_eq!(
+                err.to_string(),
+                message,
+                "from_str_error_has_input/{}",
+                input
+            );
+            assert_eq!(
+                err.downcast_ref::<datetime::ParseError>(),
+                Some(&kind),
+                "from_str_error_has_input/{}",
+                input
+            );
+        }
+
+        let err = "2021-05-01 10:00:00 +2500"
+            .parse::<DateTimeUtc>()
+            .unwrap_err();
+        assert!(err.to_string().contains("2021-05-01 10:00:00 +2500"));
+    }
 }
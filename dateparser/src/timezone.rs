@@ -6,8 +6,11 @@ use chrono::offset::FixedOffset;
 ///
 /// The additional `colon` may be used to parse a mandatory or optional `:` between hours and minutes,
 /// and should return a valid FixedOffset or `Err` when parsing fails.
+/// An offset followed by anything but its seconds, e.g. `+0800x` or `+08:00 extra`, or a name
+/// followed by anything but an offset after `GMT`, `UT` or `UTC`, e.g. `PST123`, fails rather than
+/// having the rest ignored.
 pub fn parse(s: &str) -> Result<FixedOffset> {
-    let offset = if s.contains(':') {
+    let offset = if s.contains(':') && !s.starts_with(|c: char| c.is_ascii_alphabetic()) {
         parse_offset_internal(s, colon_or_space, false)?
     } else {
         parse_offset_2822(s)?
@@ -24,28 +27,76 @@ fn parse_offset_2822(s: &str) -> Result<i32> {
         .unwrap_or(s.len());
     if upto > 0 {
         let name = &s[..upto];
-        let offset_hours = |o| Ok(o * 3600);
         if equals(name, "gmt") || equals(name, "ut") || equals(name, "utc") {
-            offset_hours(0)
-        } else if equals(name, "edt") {
-            offset_hours(-4)
-        } else if equals(name, "est") || equals(name, "cdt") {
-            offset_hours(-5)
-        } else if equals(name, "cst") || equals(name, "mdt") {
-            offset_hours(-6)
-        } else if equals(name, "mst") || equals(name, "pdt") {
-            offset_hours(-7)
-        } else if equals(name, "pst") {
-            offset_hours(-8)
+            // a signed offset right after the name, e.g. `GMT+8` or `UTC-05:30`, is added to it,
+            // which is zero, so the offset is used as is
+            match &s[upto..] {
+                "" => Ok(0),
+                offset => parse_offset_internal(offset, colon_or_space, true),
+            }
+        } else if !s[upto..].is_empty() {
+            // anything after any other name, e.g. `PST123` or `Z,extra`, is not part of it
+            Err(anyhow!("input contains invalid characters"))
         } else {
-            Ok(0) // recommended by RFC 2822: consume but treat it as -0000
+            // recommended by RFC 2822: consume an unknown name but treat it as -0000
+            Ok(named_offset(name).unwrap_or(0))
         }
     } else {
-        let offset = parse_offset_internal(s, |s| Ok(s), false)?;
+        // the minutes can be left out, e.g. `+08` is the same as `+0800`
+        let offset = parse_offset_internal(s, |s| Ok(s), true)?;
         Ok(offset)
     }
 }
 
+/// Time zone abbreviations with a known offset, and their offsets in seconds east of UTC.
+///
+/// `Zulu` is the spelled out military name of `Z`. `IST` is shared by India, Israel and Ireland;
+/// it is read as India Standard Time (+05:30), the most common meaning of the three.
+static ABBREVIATIONS: &[(&str, i32)] = &[
+    ("GMT", 0),
+    ("UT", 0),
+    ("UTC", 0),
+    ("Zulu", 0),
+    ("EDT", -4 * 3600),
+    ("EST", -5 * 3600),
+    ("CDT", -5 * 3600),
+    ("CST", -6 * 3600),
+    ("MDT", -6 * 3600),
+    ("MST", -7 * 3600),
+    ("PDT", -7 * 3600),
+    ("PST", -8 * 3600),
+    ("NST", -(3 * 3600 + 30 * 60)),
+    ("IST", 5 * 3600 + 30 * 60),
+    ("NPT", 5 * 3600 + 45 * 60),
+    ("ACST", 9 * 3600 + 30 * 60),
+];
+
+/// Returns every time zone abbreviation [`parse`] recognizes, paired with its offset in seconds
+/// east of UTC. Any other alphabetic name is read as `-0000`.
+///
+/// ```
+/// use dateparser::timezone;
+///
+/// assert!(timezone::supported_abbreviations().contains(&("PST", -8 * 3600)));
+/// ```
+pub fn supported_abbreviations() -> &'static [(&'static str, i32)] {
+    ABBREVIATIONS
+}
+
+/// Returns the offset in seconds of a time zone name, or `None` when the name is unknown.
+fn named_offset(name: &str) -> Option<i32> {
+    ABBREVIATIONS
+        .iter()
+        .find(|(abbreviation, _)| name.eq_ignore_ascii_case(abbreviation))
+        .map(|&(_, offset)| offset)
+}
+
+/// Returns true when `name` is a time zone name with a known offset, e.g. `PST`, rather than an
+/// unknown one that [`parse`] reads as `-0000`.
+pub(crate) fn is_known_name(name: &str) -> bool {
+    named_offset(name).is_some()
+}
+
 fn parse_offset_internal<F>(
     mut s: &str,
     mut consume_colon: F,
@@ -74,12 +125,25 @@ where
     };
     s = &s[1..];
 
-    // hours (00--99)
-    let hours = match digits(s)? {
-        (h1 @ b'0'..=b'9', h2 @ b'0'..=b'9') => i32::from((h1 - b'0') * 10 + (h2 - b'0')),
-        _ => return Err(anyhow!(err_invalid)),
+    // hours (00--23), an offset of a whole day or more is out of range no matter what follows.
+    // A single digit hour is taken when nothing or a colon follows it, e.g. `+8` or `+8:30`
+    let hours = match s.as_bytes() {
+        [h @ b'0'..=b'9'] | [h @ b'0'..=b'9', b':', ..] => {
+            s = &s[1..];
+            i32::from(h - b'0')
+        }
+        _ => {
+            let hours = match digits(s)? {
+                (h1 @ b'0'..=b'9', h2 @ b'0'..=b'9') => i32::from((h1 - b'0') * 10 + (h2 - b'0')),
+                _ => return Err(anyhow!(err_invalid)),
+            };
+            s = &s[2..];
+            hours
+        }
     };
-    s = &s[2..];
+    if hours >= 24 {
+        return Err(anyhow!(err_out_of_range));
+    }
 
     // colons (and possibly other separators)
     s = consume_colon(s)?;
@@ -87,17 +151,32 @@ where
     // minutes (00--59)
     // if the next two items are digits then we have to add minutes
     let minutes = if let Ok(ds) = digits(s) {
-        match ds {
+        let minutes = match ds {
             (m1 @ b'0'..=b'5', m2 @ b'0'..=b'9') => i32::from((m1 - b'0') * 10 + (m2 - b'0')),
             (b'6'..=b'9', b'0'..=b'9') => return Err(anyhow!(err_out_of_range)),
             _ => return Err(anyhow!(err_invalid)),
-        }
+        };
+        s = &s[2..];
+        minutes
     } else if allow_missing_minutes {
         0
     } else {
         return Err(anyhow!(err_too_short));
     };
 
+    // seconds (00--59) are consumed but left out of the offset, e.g. `+05:30:15` is +05:30
+    if !s.is_empty() {
+        s = consume_colon(s)?;
+    }
+    if let Ok((b'0'..=b'5', b'0'..=b'9')) = digits(s) {
+        s = &s[2..];
+    }
+
+    // anything left over, e.g. `+0800x`, is not part of an offset
+    if !s.is_empty() {
+        return Err(anyhow!(err_invalid));
+    }
+
     let seconds = hours * 3600 + minutes * 60;
     Ok(if negative { -seconds } else { seconds })
 }
@@ -129,6 +208,16 @@ fn colon_or_space(s: &str) -> Result<&str> {
 mod tests {
     use super::*;
 
+    #[test]
+    fn is_known_name() {
+        for name in ["UTC", "gmt", "UT", "PST", "edt", "Cst"] {
+            assert!(super::is_known_name(name), "is_known_name/{}", name);
+        }
+        for name in ["first", "ABC", "", "+08:00"] {
+            assert!(!super::is_known_name(name), "is_known_name/{}", name);
+        }
+    }
+
     #[test]
     fn parse() {
         let test_cases = [
@@ -138,10 +227,115 @@ mod tests {
             ("PDT", FixedOffset::west(7 * 3600)),
             ("UTC", FixedOffset::west(0)),
             ("GMT", FixedOffset::west(0)),
+            ("Zulu", FixedOffset::west(0)),
+            ("zulu", FixedOffset::west(0)),
+            ("IST", FixedOffset::east(5 * 3600 + 30 * 60)),
+            ("NPT", FixedOffset::east(5 * 3600 + 45 * 60)),
+            ("ACST", FixedOffset::east(9 * 3600 + 30 * 60)),
+            ("NST", FixedOffset::west(3 * 3600 + 30 * 60)),
+            ("+08", FixedOffset::east(8 * 3600)),
+            ("-05", FixedOffset::west(5 * 3600)),
+            ("+0800", FixedOffset::east(8 * 3600)),
+            ("-0000", FixedOffset::east(0)),
+            ("+0000", FixedOffset::east(0)),
+            ("+0830", FixedOffset::east(8 * 3600 + 30 * 60)),
+            ("+08:00", FixedOffset::east(8 * 3600)),
+            ("+05:30:15", FixedOffset::east(5 * 3600 + 30 * 60)),
+            ("+8", FixedOffset::east(8 * 3600)),
+            ("-8", FixedOffset::west(8 * 3600)),
+            ("+8:30", FixedOffset::east(8 * 3600 + 30 * 60)),
+            ("-3:30", FixedOffset::west(3 * 3600 + 30 * 60)),
+            ("GMT+8", FixedOffset::east(8 * 3600)),
+            ("GMT+08", FixedOffset::east(8 * 3600)),
+            ("GMT+08:00", FixedOffset::east(8 * 3600)),
+            ("GMT-8", FixedOffset::west(8 * 3600)),
+            ("UTC-05:00", FixedOffset::west(5 * 3600)),
+            ("UTC-0530", FixedOffset::west(5 * 3600 + 30 * 60)),
+            ("UT+0930", FixedOffset::east(9 * 3600 + 30 * 60)),
         ];
 
         for &(input, want) in test_cases.iter() {
             assert_eq!(super::parse(input).unwrap(), want, "parse/{}", input)
         }
+        assert!(super::parse("+").is_err());
+        assert!(super::parse("+8x").is_err());
+        assert!(super::parse("GMT+").is_err());
+        assert!(super::parse("UTC+x8").is_err());
+    }
+
+    #[test]
+    fn parse_trailing_garbage() {
+        for input in [
+            "+0800x",
+            "+08:00 extra",
+            "+08:00junk",
+            "+08x",
+            "-0700PDT",
+            "+05:30:15x",
+            "+05:30:159",
+            "GMT+8x",
+            "UTC-05:00 extra",
+            "PST123",
+            "PST+8",
+            "Z,extra",
+        ] {
+            let err = super::parse(input).unwrap_err();
+            assert_eq!(
+                err.to_string(),
+                "input contains invalid characters",
+                "parse_trailing_garbage/{}",
+                input
+            );
+        }
+        assert_eq!(
+            super::parse("+08:00 ").unwrap(),
+            FixedOffset::east(8 * 3600)
+        );
+    }
+
+    #[test]
+    fn supported_abbreviations() {
+        for &(name, offset) in super::supported_abbreviations() {
+            let want = FixedOffset::east(offset);
+            assert_eq!(
+                super::parse(name).unwrap(),
+                want,
+                "supported_abbreviations/{}",
+                name
+            );
+            let lower = name.to_lowercase();
+            assert_eq!(
+                super::parse(&lower).unwrap(),
+                want,
+                "supported_abbreviations/{}",
+                lower
+            );
+        }
+    }
+
+    #[test]
+    fn parse_out_of_range() {
+        for input in [
+            "+25",
+            "+25:00",
+            "-24:01",
+            "+24:00",
+            "+2500",
+            "-99",
+            "GMT+25",
+            "UTC-24:00",
+        ] {
+            let err = super::parse(input).unwrap_err();
+            assert_eq!(
+                err.to_string(),
+                "input is out of range",
+                "parse_out_of_range/{}",
+                input
+            );
+        }
+        assert_eq!(
+            super::parse("+23:59").unwrap(),
+            FixedOffset::east(23 * 3600 + 59 * 60)
+        );
     }
 }